@@ -0,0 +1,889 @@
+//! Benchmarking setup for pallet-rent
+
+use super::*;
+
+use crate::Pallet as Rent;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{
+	sp_runtime::{
+		traits::{Bounded, One, Zero},
+		Permill,
+	},
+	traits::{
+		tokens::nonfungibles::{Create, Inspect, Mutate},
+		Currency, EnsureOrigin, Get, Hooks,
+	},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+const SEED: u32 = 0;
+const RENTAL_PERIOD: u32 = 10;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+// Deterministic collectible id so that setup does not depend on the randomness source.
+fn collectible_id(seed: u32) -> CollectibleId {
+	let mut collectible_id = [0u8; 16];
+	collectible_id[..4].copy_from_slice(&seed.to_le_bytes());
+	collectible_id
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+// Mints `n` collectibles for `owner` and returns the id of the last one.
+fn mint_collectibles<T: Config>(owner: &T::AccountId, n: u32) -> CollectibleId {
+	let mut last = collectible_id(0);
+	for i in 0..n {
+		last = collectible_id(i);
+		Rent::<T>::do_mint(owner, last, None).expect("owner has room for the collectible");
+	}
+	last
+}
+
+// Fills the list of equipped collectibles of `account` with `n` entries that do not exist.
+fn fill_equips<T: Config>(account: &T::AccountId, n: u32) {
+	let equips: Vec<CollectibleId> = (0..n).map(|i| collectible_id(u32::MAX - i)).collect();
+	AccountEquips::<T>::insert(account, BoundedVec::truncate_from(equips));
+}
+
+// Fills the list of rentable collectibles with `n` entries that do not exist.
+fn fill_rentables<T: Config>(n: u32) {
+	let rentables: Vec<CollectibleId> = (0..n).map(|i| collectible_id(u32::MAX - i)).collect();
+	RentableCollectibles::<T>::put(BoundedVec::truncate_from(rentables));
+}
+
+fn collection_policy<T: Config>() -> CollectionPolicyOf<T> {
+	CollectionPolicy {
+		rentable: true,
+		minimum_price_per_block: Some(1u32.into()),
+		maximum_price_per_block: Some(1_000u32.into()),
+		minimum_rental_period: Some(1),
+		maximum_rental_period: Some(RENTAL_PERIOD * 100),
+		royalty: Permill::from_percent(10),
+	}
+}
+
+// The maximum number of pricing tiers, all of which apply to `RENTAL_PERIOD` blocks.
+fn pricing_tiers<T: Config>() -> PricingTiersOf<T> {
+	let tiers: Vec<PricingTier> = (0..T::MaxPricingTiers::get())
+		.map(|i| PricingTier { minimum_blocks: i + 1, discount: Permill::from_percent(1) })
+		.collect();
+	BoundedVec::truncate_from(tiers)
+}
+
+fn dynamic_pricing<T: Config>() -> DynamicPricingOf<T> {
+	DynamicPricing {
+		floor: 100u32.into(),
+		ceiling: 1_000u32.into(),
+		curve: PricingCurve::Quadratic,
+	}
+}
+
+// The maximum number of rental periods, all of which are within the utilization window.
+fn rental_history<T: Config>() -> RentalHistoryOf<T> {
+	let history: Vec<(T::BlockNumber, T::BlockNumber)> =
+		(0..T::MaxRentalHistory::get()).map(|i| (i.into(), (i + 1).into())).collect();
+	BoundedVec::truncate_from(history)
+}
+
+// Mints a rentable collectible that requires a security deposit, owned by a funded lessor. The
+// collectible belongs to a collection whose creator earns a royalty on its rent, and has the
+// maximum number of pricing tiers and a dynamic price with a full rental history.
+fn rentable_collectible<T: Config>() -> (T::AccountId, CollectibleId) {
+	let creator = funded_account::<T>("creator", 0);
+	Rent::<T>::create_collection(RawOrigin::Signed(creator).into(), collection_policy::<T>())
+		.expect("policy is valid");
+	let lessor = funded_account::<T>("lessor", 0);
+	let collectible_id = Rent::<T>::do_mint(&lessor, collectible_id(0), Some(0))
+		.expect("lessor has room for the collectible");
+	Rent::<T>::set_rentable(
+		RawOrigin::Signed(lessor.clone()).into(),
+		collectible_id,
+		100u32.into(),
+		1,
+		RENTAL_PERIOD * 100,
+		Some(1_000u32.into()),
+	)
+	.expect("lessor owns the collectible");
+	PricingTiers::<T>::insert(collectible_id, pricing_tiers::<T>());
+	DynamicPrices::<T>::insert(collectible_id, dynamic_pricing::<T>());
+	RentalHistory::<T>::insert(collectible_id, rental_history::<T>());
+	(lessor, collectible_id)
+}
+
+// Rents out a collectible to a funded lessee.
+fn rented_collectible<T: Config>() -> (T::AccountId, CollectibleId) {
+	let (_, collectible_id) = rentable_collectible::<T>();
+	let lessee = funded_account::<T>("lessee", 0);
+	Rent::<T>::rent(RawOrigin::Signed(lessee.clone()).into(), collectible_id, RENTAL_PERIOD, false)
+		.expect("collectible is rentable");
+	(lessee, collectible_id)
+}
+
+// Rents out a collectible whose rent is escrowed to a funded lessee.
+fn escrowed_rental<T: Config>(recurring: bool) -> (T::AccountId, T::AccountId, CollectibleId) {
+	let (lessor, collectible_id) = rentable_collectible::<T>();
+	Rent::<T>::set_payment_mode(
+		RawOrigin::Signed(lessor.clone()).into(),
+		collectible_id,
+		PaymentMode::Escrow,
+	)
+	.expect("lessor owns the collectible");
+	let lessee = funded_account::<T>("lessee", 0);
+	Rent::<T>::rent(
+		RawOrigin::Signed(lessee.clone()).into(),
+		collectible_id,
+		RENTAL_PERIOD,
+		recurring,
+	)
+	.expect("collectible is rentable");
+	(lessor, lessee, collectible_id)
+}
+
+fn agreement_of<T: Config>(lessee: &T::AccountId, collectible_id: CollectibleId) -> AgreementId {
+	LesseeCollectibles::<T>::get(lessee, collectible_id)
+		.expect("collectible is rented")
+		.agreement_id
+}
+
+// Makes a rental offer for a collectible from a funded account, expiring in `expiry`.
+fn rental_offer<T: Config>(
+	collectible_id: CollectibleId,
+	index: u32,
+	expiry: T::BlockNumber,
+) -> T::AccountId {
+	let lessee = funded_account::<T>("lessee", index);
+	Rent::<T>::make_rental_offer(
+		RawOrigin::Signed(lessee.clone()).into(),
+		collectible_id,
+		100u32.into(),
+		RENTAL_PERIOD,
+		expiry,
+	)
+	.expect("collectible exists");
+	lessee
+}
+
+// Mints a collectible into the collection created by `rentable_collectible` and auctions its next
+// rental period, with a bid from a funded account.
+fn auctioned_collectible<T: Config>(
+	lessor: &T::AccountId,
+	index: u32,
+	end: T::BlockNumber,
+) -> CollectibleId {
+	let collectible_id = Rent::<T>::do_mint(lessor, collectible_id(index), Some(0))
+		.expect("lessor has room for the collectible");
+	Rent::<T>::open_auction(
+		RawOrigin::Signed(lessor.clone()).into(),
+		collectible_id,
+		RENTAL_PERIOD,
+		100u32.into(),
+		10u32.into(),
+		end,
+	)
+	.expect("collectible is not rented");
+	let bidder = funded_account::<T>("bidder", index);
+	Rent::<T>::bid(RawOrigin::Signed(bidder).into(), collectible_id, 100u32.into())
+		.expect("bid is the reserve price");
+	collectible_id
+}
+
+// Fills the calendar of a collectible with `n` reservations far in the future.
+fn fill_reservations<T: Config>(collectible_id: CollectibleId, n: u32) {
+	let reservations: Vec<Reservation<T>> = (0..n)
+		.map(|i| {
+			let start: T::BlockNumber = (1_000 + i * 2 * RENTAL_PERIOD).into();
+			Reservation {
+				lessee: account("reserver", i, SEED),
+				start,
+				end: start + RENTAL_PERIOD.into(),
+				price_per_block: 100u32.into(),
+				prepaid: Zero::zero(),
+				tier: None,
+				refundable_until: start,
+			}
+		})
+		.collect();
+	Reservations::<T>::insert(collectible_id, BoundedVec::truncate_from(reservations));
+}
+
+// Rents out a collectible and lets the lessor claim part of the security deposit.
+fn claimed_deposit<T: Config>() -> (T::AccountId, CollectibleId, AgreementId) {
+	let (lessee, collectible_id) = rented_collectible::<T>();
+	let agreement_id = agreement_of::<T>(&lessee, collectible_id);
+	let deposit = Deposits::<T>::get(agreement_id).expect("collectible requires a deposit");
+	Rent::<T>::claim_deposit(RawOrigin::Signed(deposit.lessor).into(), agreement_id, 100u32.into())
+		.expect("claim is within the deposit");
+	(lessee, collectible_id, agreement_id)
+}
+
+fn attribute_key<T: Config>(seed: u32) -> AttributeKeyOf<T> {
+	let mut key = seed.to_le_bytes().to_vec();
+	key.resize(T::KeyLimit::get() as usize, 0);
+	BoundedVec::truncate_from(key)
+}
+
+fn attribute_value<T: Config>() -> AttributeValueOf<T> {
+	BoundedVec::truncate_from(vec![0u8; T::ValueLimit::get() as usize])
+}
+
+// Mints a collectible for a funded lessor with metadata of maximum length and all but one of
+// its attributes set.
+fn collectible_with_metadata<T: Config>() -> (T::AccountId, CollectibleId) {
+	let lessor = funded_account::<T>("lessor", 0);
+	let collectible_id = mint_collectibles::<T>(&lessor, 1);
+	let data = vec![0u8; T::MetadataLimit::get() as usize];
+	Rent::<T>::set_metadata(
+		RawOrigin::Signed(lessor.clone()).into(),
+		collectible_id,
+		BoundedVec::truncate_from(data),
+	)
+	.expect("lessor owns the collectible");
+	for i in 0..T::MaxAttributes::get().saturating_sub(1) {
+		Rent::<T>::set_attribute(
+			RawOrigin::Signed(lessor.clone()).into(),
+			collectible_id,
+			attribute_key::<T>(i),
+			attribute_value::<T>(),
+		)
+		.expect("collectible has room for the attribute");
+	}
+	(lessor, collectible_id)
+}
+
+// Mints an external NFT for `owner`, who pays the deposits of the NFT pallet.
+fn external_nft<T: Config>(owner: &T::AccountId) -> (T::NftCollectionId, T::NftId)
+where
+	T::Nfts: Create<T::AccountId> + Mutate<T::AccountId>,
+{
+	let collection = T::BenchmarkHelper::collection(0);
+	let item = T::BenchmarkHelper::item(0);
+	T::Nfts::create_collection(&collection, owner, owner).expect("collection does not exist");
+	T::Nfts::mint_into(&collection, &item, owner).expect("item does not exist");
+	(collection, item)
+}
+
+benchmarks! {
+	where_clause { where T::Nfts: Create<T::AccountId> + Mutate<T::AccountId> }
+
+	mint {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		mint_collectibles::<T>(&caller, n);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert_eq!(LessorCollectibles::<T>::get(&caller).unwrap_or_default().len() as u32, n + 1);
+	}
+
+	burn {
+		let n in 1 .. T::MaximumOwned::get();
+		let r in 0 .. T::MaxReservations::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		mint_collectibles::<T>(&caller, n - 1);
+		let (collection, item) = external_nft::<T>(&caller);
+		Rent::<T>::wrap_nft(RawOrigin::Signed(caller.clone()).into(), collection, item)?;
+		let collectible_id =
+			NftCollectibles::<T>::get(collection, item).expect("the NFT was just wrapped");
+		fill_equips::<T>(&caller, n - 1);
+		fill_reservations::<T>(collectible_id, r);
+		AccountEquips::<T>::mutate(&caller, |equips| {
+			equips
+				.get_or_insert_with(Default::default)
+				.try_push(collectible_id)
+				.expect("equips are below the maximum")
+		});
+	}: _(RawOrigin::Signed(caller.clone()), collectible_id)
+	verify {
+		assert!(!Collectibles::<T>::contains_key(collectible_id));
+		assert!(Reservations::<T>::get(collectible_id).is_empty());
+		assert_eq!(T::Nfts::owner(&collection, &item), Some(caller));
+	}
+
+	set_rentable {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		let collectible_id = mint_collectibles::<T>(&caller, 1);
+		fill_rentables::<T>(n);
+		fill_equips::<T>(&caller, T::MaximumOwned::get());
+		let price_per_block: BalanceOf<T> = 100u32.into();
+		let deposit: Option<BalanceOf<T>> = Some(1_000u32.into());
+	}: _(RawOrigin::Signed(caller), collectible_id, price_per_block, 1, RENTAL_PERIOD, deposit)
+	verify {
+		assert_last_event::<T>(
+			Event::RentMadeAvailable { collectible: collectible_id, price_per_block }.into()
+		);
+	}
+
+	rent {
+		let (_, collectible_id) = rentable_collectible::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), collectible_id, RENTAL_PERIOD, true)
+	verify {
+		assert!(LesseeCollectibles::<T>::contains_key(&caller, collectible_id));
+	}
+
+	set_unrentable {
+		let n in 1 .. T::MaximumOwned::get();
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		fill_rentables::<T>(n - 1);
+		RentableCollectibles::<T>::mutate(|rentables| {
+			rentables.try_push(collectible_id).expect("rentables are below the maximum")
+		});
+	}: _(RawOrigin::Signed(lessor), collectible_id)
+	verify {
+		assert_last_event::<T>(Event::RentMadeUnavailable { collectible: collectible_id }.into());
+	}
+
+	set_recurring {
+		let (lessee, collectible_id) = rented_collectible::<T>();
+		let agreement = LesseeCollectibles::<T>::get(&lessee, collectible_id)
+			.expect("collectible is rented")
+			.agreement_id;
+	}: _(RawOrigin::Signed(lessee), collectible_id, true)
+	verify {
+		assert_last_event::<T>(
+			Event::RentalSetRecurring { agreement, collectible: collectible_id, recurring: true }
+				.into()
+		);
+	}
+
+	extend_rent {
+		let (lessee, collectible_id) = rented_collectible::<T>();
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id, RENTAL_PERIOD.into())
+	verify {
+		let rental = LesseeCollectibles::<T>::get(&lessee, collectible_id).unwrap();
+		assert_eq!(
+			rental.next_rent_block,
+			frame_system::Pallet::<T>::block_number() + (RENTAL_PERIOD * 2).into()
+		);
+	}
+
+	process_rental {
+		// Renewing an escrowed rental releases and reserves rent, which is the most expensive path
+		let (_, lessee, collectible_id) = escrowed_rental::<T>(true);
+		let next_rent_block = LesseeCollectibles::<T>::get(&lessee, collectible_id)
+			.expect("collectible is rented")
+			.next_rent_block;
+		Escalations::<T>::insert(
+			agreement_of::<T>(&lessee, collectible_id),
+			ScheduledEscalation {
+				escalation: Escalation::PerRenewal(Permill::from_percent(1)),
+				effective_from: One::one(),
+			},
+		);
+		frame_system::Pallet::<T>::set_block_number(next_rent_block);
+	}: {
+		Rent::<T>::on_initialize(next_rent_block);
+	}
+	verify {
+		let rental = LesseeCollectibles::<T>::get(&lessee, collectible_id).unwrap();
+		assert_eq!(rental.next_rent_block, next_rent_block + RENTAL_PERIOD.into());
+	}
+
+	equip_collectible {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		let collectible_id = mint_collectibles::<T>(&caller, 1);
+		fill_equips::<T>(&caller, n);
+	}: _(RawOrigin::Signed(caller.clone()), collectible_id)
+	verify {
+		assert_last_event::<T>(
+			Event::CollectibleEquipped { account: caller, collectible: collectible_id }.into()
+		);
+	}
+
+	unequip_collectible {
+		let n in 1 .. T::MaximumOwned::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let collectible_id = mint_collectibles::<T>(&caller, 1);
+		fill_equips::<T>(&caller, n - 1);
+		AccountEquips::<T>::mutate(&caller, |equips| {
+			equips
+				.get_or_insert_with(Default::default)
+				.try_push(collectible_id)
+				.expect("equips are below the maximum")
+		});
+	}: _(RawOrigin::Signed(caller.clone()), collectible_id)
+	verify {
+		assert_last_event::<T>(
+			Event::CollectibleUnequipped { account: caller, collectible: collectible_id }.into()
+		);
+	}
+
+	set_refund_policy {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let policy = RefundPolicy::Partial(Permill::from_percent(50));
+	}: _(RawOrigin::Signed(lessor), collectible_id, policy)
+	verify {
+		assert_last_event::<T>(Event::RefundPolicySet { collectible: collectible_id, policy }.into());
+	}
+
+	end_rental {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		Rent::<T>::set_refund_policy(
+			RawOrigin::Signed(lessor).into(),
+			collectible_id,
+			RefundPolicy::ProRata,
+		)?;
+		let lessee = funded_account::<T>("lessee", 0);
+		Rent::<T>::rent(
+			RawOrigin::Signed(lessee.clone()).into(),
+			collectible_id,
+			RENTAL_PERIOD,
+			true,
+		)?;
+		Rent::<T>::equip_collectible(RawOrigin::Signed(lessee.clone()).into(), collectible_id)?;
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id)
+	verify {
+		assert!(!LesseeCollectibles::<T>::contains_key(&lessee, collectible_id));
+	}
+
+	set_payment_mode {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+	}: _(RawOrigin::Signed(lessor), collectible_id, PaymentMode::Escrow)
+	verify {
+		assert_last_event::<T>(
+			Event::PaymentModeSet { collectible: collectible_id, mode: PaymentMode::Escrow }.into()
+		);
+	}
+
+	claim_rent {
+		let (lessor, lessee, collectible_id) = escrowed_rental::<T>(false);
+		let agreement_id = LesseeCollectibles::<T>::get(&lessee, collectible_id)
+			.expect("collectible is rented")
+			.agreement_id;
+		let block_number = frame_system::Pallet::<T>::block_number() + (RENTAL_PERIOD / 2).into();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+	}: _(RawOrigin::Signed(lessor), collectible_id)
+	verify {
+		let agreement = RentalAgreements::<T>::get(agreement_id).unwrap();
+		assert_eq!(agreement.settled_until, block_number);
+	}
+
+	claim_deposit {
+		let (lessee, collectible_id) = rented_collectible::<T>();
+		let agreement_id = agreement_of::<T>(&lessee, collectible_id);
+		let lessor = Collectibles::<T>::get(collectible_id).expect("collectible exists").lessor;
+	}: _(RawOrigin::Signed(lessor), agreement_id, 100u32.into())
+	verify {
+		assert!(Deposits::<T>::get(agreement_id).unwrap().claim.is_some());
+	}
+
+	dispute_deposit_claim {
+		let (lessee, _, agreement_id) = claimed_deposit::<T>();
+	}: _(RawOrigin::Signed(lessee), agreement_id)
+	verify {
+		assert!(Deposits::<T>::get(agreement_id).unwrap().claim.unwrap().disputed);
+	}
+
+	settle_deposit_claim {
+		let (lessee, collectible_id, agreement_id) = claimed_deposit::<T>();
+		// Settling the claim of an ended rental also returns the rest of the deposit
+		Rent::<T>::end_rental(RawOrigin::Signed(lessee).into(), collectible_id)?;
+		let block_number = frame_system::Pallet::<T>::block_number() +
+			T::DepositDisputePeriod::get() +
+			One::one();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), agreement_id)
+	verify {
+		assert!(!Deposits::<T>::contains_key(agreement_id));
+	}
+
+	resolve_deposit_dispute {
+		let (lessee, collectible_id, agreement_id) = claimed_deposit::<T>();
+		Rent::<T>::dispute_deposit_claim(RawOrigin::Signed(lessee.clone()).into(), agreement_id)?;
+		Rent::<T>::end_rental(RawOrigin::Signed(lessee).into(), collectible_id)?;
+		let origin = T::DisputeOrigin::successful_origin();
+	}: _<T::RuntimeOrigin>(origin, agreement_id, 100u32.into())
+	verify {
+		assert!(!Deposits::<T>::contains_key(agreement_id));
+	}
+
+	pay_overdue {
+		let (lessee, collectible_id) = rented_collectible::<T>();
+		Rent::<T>::set_recurring(RawOrigin::Signed(lessee.clone()).into(), collectible_id, true)?;
+		let next_rent_block = LesseeCollectibles::<T>::get(&lessee, collectible_id)
+			.expect("collectible is rented")
+			.next_rent_block;
+		// Renewing the rental fails for lack of funds, which starts the grace period
+		T::Currency::make_free_balance_be(&lessee, T::Currency::minimum_balance());
+		frame_system::Pallet::<T>::set_block_number(next_rent_block);
+		Rent::<T>::on_initialize(next_rent_block);
+		T::Currency::make_free_balance_be(&lessee, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id)
+	verify {
+		let rental = LesseeCollectibles::<T>::get(&lessee, collectible_id).unwrap();
+		assert_eq!(rental.status, RentalStatus::Active);
+	}
+
+	transfer {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let to: T::AccountId = account("buyer", 0, SEED);
+		let owned: Vec<CollectibleId> = (0..n).map(|i| collectible_id(u32::MAX - i)).collect();
+		LessorCollectibles::<T>::insert(&to, BoundedVec::truncate_from(owned));
+		let (lessor, _, collectible_id) = escrowed_rental::<T>(true);
+		// Part of the escrowed rent is released to the previous lessor
+		let block_number = frame_system::Pallet::<T>::block_number() + One::one();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+	}: _(RawOrigin::Signed(lessor.clone()), collectible_id, to.clone(), true)
+	verify {
+		assert_eq!(Collectibles::<T>::get(collectible_id).unwrap().lessor, to.clone());
+		assert_last_event::<T>(
+			Event::TransferSucceeded { from: lessor, to, collectible: collectible_id }.into(),
+		);
+	}
+
+	list_for_sale {
+		let caller: T::AccountId = whitelisted_caller();
+		let collectible_id = mint_collectibles::<T>(&caller, 1);
+	}: _(RawOrigin::Signed(caller), collectible_id, 1_000u32.into(), true)
+	verify {
+		assert!(SaleListings::<T>::contains_key(collectible_id));
+	}
+
+	unlist_from_sale {
+		let caller: T::AccountId = whitelisted_caller();
+		let collectible_id = mint_collectibles::<T>(&caller, 1);
+		Rent::<T>::list_for_sale(
+			RawOrigin::Signed(caller.clone()).into(),
+			collectible_id,
+			1_000u32.into(),
+			true,
+		)?;
+	}: _(RawOrigin::Signed(caller), collectible_id)
+	verify {
+		assert!(!SaleListings::<T>::contains_key(collectible_id));
+	}
+
+	buy {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let buyer = funded_account::<T>("buyer", 0);
+		let owned: Vec<CollectibleId> = (0..n).map(|i| collectible_id(u32::MAX - i)).collect();
+		LessorCollectibles::<T>::insert(&buyer, BoundedVec::truncate_from(owned));
+		let (lessor, _, collectible_id) = escrowed_rental::<T>(true);
+		Rent::<T>::list_for_sale(
+			RawOrigin::Signed(lessor.clone()).into(),
+			collectible_id,
+			1_000u32.into(),
+			true,
+		)?;
+		let block_number = frame_system::Pallet::<T>::block_number() + One::one();
+		frame_system::Pallet::<T>::set_block_number(block_number);
+	}: _(RawOrigin::Signed(buyer.clone()), collectible_id, 1_000u32.into())
+	verify {
+		assert_last_event::<T>(
+			Event::CollectibleSold {
+				collectible: collectible_id,
+				seller: lessor,
+				buyer,
+				price: 1_000u32.into(),
+			}
+			.into(),
+		);
+	}
+
+	set_metadata {
+		let (lessor, collectible_id) = collectible_with_metadata::<T>();
+		let data: BoundedVec<u8, T::MetadataLimit> =
+			BoundedVec::truncate_from(vec![1u8; T::MetadataLimit::get() as usize]);
+	}: _(RawOrigin::Signed(lessor), collectible_id, data.clone())
+	verify {
+		assert_last_event::<T>(Event::MetadataSet { collectible: collectible_id, data }.into());
+	}
+
+	clear_metadata {
+		let (lessor, collectible_id) = collectible_with_metadata::<T>();
+	}: _(RawOrigin::Signed(lessor), collectible_id)
+	verify {
+		assert!(!MetadataOf::<T>::contains_key(collectible_id));
+	}
+
+	set_attribute {
+		let (lessor, collectible_id) = collectible_with_metadata::<T>();
+		let key = attribute_key::<T>(u32::MAX);
+	}: _(RawOrigin::Signed(lessor), collectible_id, key.clone(), attribute_value::<T>())
+	verify {
+		assert_last_event::<T>(
+			Event::AttributeSet { collectible: collectible_id, key, value: attribute_value::<T>() }
+				.into(),
+		);
+	}
+
+	clear_attribute {
+		let (lessor, collectible_id) = collectible_with_metadata::<T>();
+		let key = attribute_key::<T>(0);
+	}: _(RawOrigin::Signed(lessor), collectible_id, key.clone())
+	verify {
+		assert_last_event::<T>(Event::AttributeCleared { collectible: collectible_id, key }.into());
+	}
+
+	create_collection {
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller.clone()), collection_policy::<T>())
+	verify {
+		assert_last_event::<T>(Event::CollectionCreated { collection: 0, creator: caller }.into());
+	}
+
+	set_collection_policy {
+		let caller: T::AccountId = whitelisted_caller();
+		Rent::<T>::create_collection(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_policy::<T>(),
+		)?;
+		let policy = CollectionPolicy { rentable: false, ..collection_policy::<T>() };
+	}: _(RawOrigin::Signed(caller), 0, policy)
+	verify {
+		assert_last_event::<T>(Event::CollectionPolicySet { collection: 0, policy }.into());
+	}
+
+	mint_into_collection {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		Rent::<T>::create_collection(
+			RawOrigin::Signed(caller.clone()).into(),
+			collection_policy::<T>(),
+		)?;
+		mint_collectibles::<T>(&caller, n);
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert_eq!(LessorCollectibles::<T>::get(&caller).unwrap_or_default().len() as u32, n + 1);
+	}
+
+	set_pricing_tiers {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let tiers = pricing_tiers::<T>();
+	}: _(RawOrigin::Signed(lessor), collectible_id, tiers.clone())
+	verify {
+		assert_last_event::<T>(Event::PricingTiersSet { collectible: collectible_id, tiers }.into());
+	}
+
+	set_dynamic_pricing {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let pricing = dynamic_pricing::<T>();
+	}: _(RawOrigin::Signed(lessor), collectible_id, Some(pricing))
+	verify {
+		assert_eq!(DynamicPrices::<T>::get(collectible_id), Some(pricing));
+	}
+
+	schedule_escalation {
+		let (lessee, collectible_id) = rented_collectible::<T>();
+		let lessor = Collectibles::<T>::get(collectible_id).expect("collectible exists").lessor;
+		DynamicPrices::<T>::remove(collectible_id);
+		let escalation = Escalation::NewPrice(200u32.into());
+	}: _(RawOrigin::Signed(lessor), collectible_id, Some(escalation))
+	verify {
+		let agreement = agreement_of::<T>(&lessee, collectible_id);
+		assert_eq!(Escalations::<T>::get(agreement).unwrap().escalation, escalation);
+	}
+
+	make_rental_offer {
+		// Replacing an earlier offer is the most expensive path
+		let (_, collectible_id) = rentable_collectible::<T>();
+		let lessee = rental_offer::<T>(collectible_id, 0, 10u32.into());
+		let expiry: T::BlockNumber = 20u32.into();
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id, 200u32.into(), RENTAL_PERIOD, expiry)
+	verify {
+		assert_eq!(RentalOffers::<T>::get(collectible_id, &lessee).unwrap().expiry, expiry);
+	}
+
+	accept_offer {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let lessee = rental_offer::<T>(collectible_id, 0, 10u32.into());
+	}: _(RawOrigin::Signed(lessor), collectible_id, lessee.clone())
+	verify {
+		assert!(LesseeCollectibles::<T>::contains_key(&lessee, collectible_id));
+	}
+
+	reject_offer {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let lessee = rental_offer::<T>(collectible_id, 0, 10u32.into());
+	}: _(RawOrigin::Signed(lessor), collectible_id, lessee.clone())
+	verify {
+		assert!(!RentalOffers::<T>::contains_key(collectible_id, &lessee));
+	}
+
+	withdraw_offer {
+		let (_, collectible_id) = rentable_collectible::<T>();
+		let lessee = rental_offer::<T>(collectible_id, 0, 10u32.into());
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id)
+	verify {
+		assert!(!RentalOffers::<T>::contains_key(collectible_id, &lessee));
+	}
+
+	expire_offers {
+		let n in 0 .. T::MaxOffersPerBlock::get();
+		let (_, collectible_id) = rentable_collectible::<T>();
+		let expiry: T::BlockNumber = 10u32.into();
+		for i in 0..n {
+			rental_offer::<T>(collectible_id, i, expiry);
+		}
+	}: {
+		Rent::<T>::expire_offers(expiry);
+	}
+	verify {
+		assert_eq!(RentalOffers::<T>::iter_prefix(collectible_id).count(), 0);
+	}
+
+	open_auction {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let end: T::BlockNumber = 20u32.into();
+	}: _(
+		RawOrigin::Signed(lessor),
+		collectible_id,
+		RENTAL_PERIOD,
+		100u32.into(),
+		10u32.into(),
+		end
+	)
+	verify {
+		assert_eq!(RentalAuctions::<T>::get(collectible_id).unwrap().end, end);
+	}
+
+	bid {
+		// Outbidding returns the reserved rent of the previous highest bidder
+		let (lessor, _) = rentable_collectible::<T>();
+		let collectible_id = auctioned_collectible::<T>(&lessor, 1, 20u32.into());
+		let bidder = funded_account::<T>("bidder", 2);
+	}: _(RawOrigin::Signed(bidder.clone()), collectible_id, 200u32.into())
+	verify {
+		let auction = RentalAuctions::<T>::get(collectible_id).unwrap();
+		assert_eq!(auction.highest_bid, Some((bidder, 200u32.into())));
+	}
+
+	cancel_auction {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		Rent::<T>::open_auction(
+			RawOrigin::Signed(lessor.clone()).into(),
+			collectible_id,
+			RENTAL_PERIOD,
+			100u32.into(),
+			10u32.into(),
+			20u32.into(),
+		)
+		.expect("collectible is not rented");
+	}: _(RawOrigin::Signed(lessor), collectible_id)
+	verify {
+		assert!(!RentalAuctions::<T>::contains_key(collectible_id));
+	}
+
+	reserve {
+		// The reservation is inserted before all others in an almost full calendar
+		let (_, collectible_id) = rentable_collectible::<T>();
+		fill_reservations::<T>(collectible_id, T::MaxReservations::get() - 1);
+		let lessee = funded_account::<T>("lessee", 0);
+		let start: T::BlockNumber = 10u32.into();
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id, start, start + RENTAL_PERIOD.into())
+	verify {
+		assert_eq!(Reservations::<T>::get(collectible_id)[0].lessee, lessee);
+	}
+
+	cancel_reservation {
+		// A reservation cancelled after the cutoff pays its rent to the lessor
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		Rent::<T>::set_reservation_cutoff(
+			RawOrigin::Signed(lessor).into(),
+			collectible_id,
+			100u32.into(),
+		)?;
+		fill_reservations::<T>(collectible_id, T::MaxReservations::get() - 1);
+		let lessee = funded_account::<T>("lessee", 0);
+		let start: T::BlockNumber = 10u32.into();
+		Rent::<T>::reserve(
+			RawOrigin::Signed(lessee.clone()).into(),
+			collectible_id,
+			start,
+			start + RENTAL_PERIOD.into(),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(One::one());
+	}: _(RawOrigin::Signed(lessee.clone()), collectible_id, start)
+	verify {
+		assert_last_event::<T>(
+			Event::ReservationCancelled {
+				collectible: collectible_id,
+				lessee,
+				start,
+				refunded: false,
+			}
+			.into()
+		);
+	}
+
+	set_reservation_cutoff {
+		let (lessor, collectible_id) = rentable_collectible::<T>();
+		let cutoff: T::BlockNumber = 100u32.into();
+	}: _(RawOrigin::Signed(lessor), collectible_id, cutoff)
+	verify {
+		assert_eq!(ReservationCutoffs::<T>::get(collectible_id), cutoff);
+	}
+
+	activate_reservation {
+		// The pending rentals processing starts a reservation of a collectible with a full calendar
+		let (_, collectible_id) = rentable_collectible::<T>();
+		fill_reservations::<T>(collectible_id, T::MaxReservations::get() - 1);
+		let lessee = funded_account::<T>("lessee", 0);
+		let start: T::BlockNumber = 10u32.into();
+		Rent::<T>::reserve(
+			RawOrigin::Signed(lessee.clone()).into(),
+			collectible_id,
+			start,
+			start + RENTAL_PERIOD.into(),
+		)?;
+		frame_system::Pallet::<T>::set_block_number(start);
+	}: {
+		Rent::<T>::on_initialize(start);
+	}
+	verify {
+		assert_eq!(Collectibles::<T>::get(collectible_id).unwrap().lessee, Some(lessee));
+	}
+
+	claim_refund {
+		let lessor = funded_account::<T>("lessor", 0);
+		let caller: T::AccountId = whitelisted_caller();
+		let amount: BalanceOf<T> = 1_000u32.into();
+		OwedRefunds::<T>::insert(&lessor, &caller, amount);
+	}: _(RawOrigin::Signed(caller.clone()), lessor.clone())
+	verify {
+		assert_last_event::<T>(Event::OwedRefundPaid { lessor, lessee: caller, amount }.into());
+	}
+
+	settle_auctions {
+		let n in 0 .. T::MaxAuctionsPerBlock::get();
+		let (lessor, _) = rentable_collectible::<T>();
+		let end: T::BlockNumber = 20u32.into();
+		for i in 1..=n {
+			auctioned_collectible::<T>(&lessor, i, end);
+		}
+	}: {
+		Rent::<T>::settle_auctions(end);
+	}
+	verify {
+		for i in 1..=n {
+			assert!(Collectibles::<T>::get(collectible_id(i)).unwrap().lessee.is_some());
+		}
+	}
+
+	wrap_nft {
+		let n in 0 .. T::MaximumOwned::get() - 1;
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		mint_collectibles::<T>(&caller, n);
+		let (collection, item) = external_nft::<T>(&caller);
+	}: _(RawOrigin::Signed(caller.clone()), collection, item)
+	verify {
+		assert_eq!(T::Nfts::owner(&collection, &item), Some(Rent::<T>::account_id()));
+		assert!(NftCollectibles::<T>::contains_key(collection, item));
+	}
+
+	impl_benchmark_test_suite!(Rent, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
+}
@@ -0,0 +1,184 @@
+//! Implementations of the `nonfungibles` traits for collectibles.
+//!
+//! Collectibles are addressed by their collection, where `None` stands for the collectibles
+//! minted outside of any collection. A rented or auctioned collectible can neither be transferred
+//! nor burned.
+
+use super::*;
+use frame_support::{
+	ensure,
+	sp_runtime::{DispatchError, DispatchResult, Permill},
+	traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
+	BoundedVec,
+};
+use sp_std::prelude::*;
+
+impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
+	type ItemId = CollectibleId;
+	type CollectionId = Option<CollectionId>;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<T::AccountId> {
+		Collectibles::<T>::get(item)
+			.filter(|collectible| collectible.collection == *collection)
+			.map(|collectible| collectible.lessor)
+	}
+
+	fn collection_owner(collection: &Self::CollectionId) -> Option<T::AccountId> {
+		collection.and_then(Collections::<T>::get).map(|collection| collection.creator)
+	}
+
+	/// Returns the attribute of `item` with the given `key`. An empty key returns the metadata
+	/// of `item`.
+	fn attribute(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		key: &[u8],
+	) -> Option<Vec<u8>> {
+		Self::owner(collection, item)?;
+		let metadata = MetadataOf::<T>::get(item)?;
+
+		if key.is_empty() {
+			return Some(metadata.data.into())
+		}
+
+		metadata
+			.attributes
+			.into_iter()
+			.find(|(k, _)| k.as_slice() == key)
+			.map(|(_, value)| value.into())
+	}
+
+	/// Returns `true` if `item` exists in `collection` and is neither rented nor auctioned.
+	fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+		Collectibles::<T>::get(item).map_or(false, |collectible| {
+			collectible.collection == *collection &&
+				collectible.lessee.is_none() &&
+				!RentalAuctions::<T>::contains_key(item)
+		})
+	}
+}
+
+impl<T: Config> Create<T::AccountId> for Pallet<T> {
+	/// Creates a collection with a policy that places no restrictions on renting its items.
+	fn create_collection(
+		collection: &Self::CollectionId,
+		who: &T::AccountId,
+		_admin: &T::AccountId,
+	) -> DispatchResult {
+		let collection_id = collection.ok_or(Error::<T>::CollectionRequired)?;
+		ensure!(!Collections::<T>::contains_key(collection_id), Error::<T>::CollectionExists);
+
+		let next_collection_id = collection_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+		NextCollectionId::<T>::mutate(|next| *next = (*next).max(next_collection_id));
+
+		let policy = CollectionPolicy {
+			rentable: true,
+			minimum_price_per_block: None,
+			maximum_price_per_block: None,
+			minimum_rental_period: None,
+			maximum_rental_period: None,
+			royalty: Permill::zero(),
+		};
+		Collections::<T>::insert(collection_id, Collection { creator: who.clone(), policy });
+
+		Self::deposit_event(Event::CollectionCreated {
+			collection: collection_id,
+			creator: who.clone(),
+		});
+
+		Ok(())
+	}
+}
+
+impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
+	fn mint_into(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		if let Some(collection_id) = collection {
+			ensure!(Collections::<T>::contains_key(collection_id), Error::<T>::NoCollection);
+		}
+
+		Self::do_mint(who, *item, *collection)?;
+
+		Ok(())
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		maybe_check_owner: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let collectible = Self::collectible_in(collection, item)?;
+
+		if let Some(owner) = maybe_check_owner {
+			ensure!(collectible.lessor == *owner, Error::<T>::NotLessor);
+		}
+		ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+
+		Self::do_burn(collectible)
+	}
+
+	/// Sets the attribute of `item` with the given `key`, reserving the deposit from its lessor.
+	/// An empty key sets the metadata of `item`.
+	fn set_attribute(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		key: &[u8],
+		value: &[u8],
+	) -> DispatchResult {
+		let collectible = Self::collectible_in(collection, item)?;
+		ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+
+		let lessor = collectible.lessor;
+		let mut metadata = Self::metadata_or_default(*item, &lessor);
+
+		if key.is_empty() {
+			metadata.data =
+				BoundedVec::try_from(value.to_vec()).map_err(|_| Error::<T>::AttributeTooLong)?;
+		} else {
+			let key = AttributeKeyOf::<T>::try_from(key.to_vec())
+				.map_err(|_| Error::<T>::AttributeTooLong)?;
+			let value = AttributeValueOf::<T>::try_from(value.to_vec())
+				.map_err(|_| Error::<T>::AttributeTooLong)?;
+
+			match metadata.attributes.iter_mut().find(|(k, _)| *k == key) {
+				Some((_, v)) => *v = value,
+				None => metadata
+					.attributes
+					.try_push((key, value))
+					.map_err(|_| Error::<T>::TooManyAttributes)?,
+			}
+		}
+
+		Self::store_metadata(&lessor, *item, metadata)
+	}
+}
+
+impl<T: Config> Transfer<T::AccountId> for Pallet<T> {
+	fn transfer(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		destination: &T::AccountId,
+	) -> DispatchResult {
+		let collectible = Self::collectible_in(collection, item)?;
+		ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+		ensure!(collectible.lessor != *destination, Error::<T>::TransferToSelf);
+
+		Self::do_transfer(collectible, destination.clone())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	// Fetches a collectible, failing if it is not part of `collection`.
+	fn collectible_in(
+		collection: &Option<CollectionId>,
+		item: &CollectibleId,
+	) -> Result<Collectible<T>, DispatchError> {
+		let collectible = Self::fetch_collectible(*item)?;
+		ensure!(collectible.collection == *collection, Error::<T>::NoCollectible);
+
+		Ok(collectible)
+	}
+}
@@ -14,30 +14,57 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+mod impl_nonfungibles;
+pub mod migrations;
+pub mod traits;
 mod utils;
+pub mod weights;
 
 pub use pallet::*;
+pub use traits::{LinearPricing, OnRentalLifecycle, RentalInspect, RentalPricing};
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		ensure,
 		pallet_prelude::*,
-		traits::{Currency, Get, Randomness},
+		sp_runtime::{
+			traits::{
+				AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedMul, CheckedSub, One,
+				Saturating, Zero,
+			},
+			Permill,
+		},
+		storage::with_storage_layer,
+		traits::{
+			tokens::nonfungibles::{Inspect as NftInspect, Transfer as NftTransfer},
+			BalanceStatus, Currency, Get, Randomness, ReservableCurrency,
+		},
+		PalletId,
 	};
 	use frame_system::pallet_prelude::{OriginFor, *};
 
-	use scale_info::prelude::vec;
+	use crate::{
+		traits::{OnRentalLifecycle, RentalPricing},
+		utils::convert_to_primitive,
+		WeightInfo,
+	};
 
-	use crate::utils::convert_to_primitive;
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 		type CollectionRandomness: Randomness<Self::Hash, Self::BlockNumber>;
 
 		#[pallet::constant]
@@ -46,26 +73,178 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaximumRentablesPerBlock: Get<u32>;
 
+		/// The number of consecutive blocks searched for room in `PendingRentals` before a
+		/// rental is refused.
+		#[pallet::constant]
+		type MaximumSchedulingProbes: Get<u32>;
+
+		/// The maximum weight `on_initialize` may spend on processing pending rentals. Rentals
+		/// that do not fit are carried over to `on_idle` and the following blocks.
+		#[pallet::constant]
+		type MaxRentalProcessingWeight: Get<Weight>;
+
+		/// The number of blocks a lessee has to pay the rent of a recurring rental that could
+		/// not be collected before the rental is terminated.
+		#[pallet::constant]
+		type PaymentGracePeriod: Get<Self::BlockNumber>;
+
+		/// The number of blocks a lessee has to dispute a claim on their security deposit.
+		#[pallet::constant]
+		type DepositDisputePeriod: Get<Self::BlockNumber>;
+
+		/// The origin that resolves disputed claims on security deposits.
+		type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum length of the metadata of a collectible.
+		#[pallet::constant]
+		type MetadataLimit: Get<u32>;
+
+		/// The maximum length of an attribute key.
+		#[pallet::constant]
+		type KeyLimit: Get<u32>;
+
+		/// The maximum length of an attribute value.
+		#[pallet::constant]
+		type ValueLimit: Get<u32>;
+
+		/// The maximum number of attributes of a collectible.
+		#[pallet::constant]
+		type MaxAttributes: Get<u32>;
+
+		/// The base deposit for storing metadata or attributes of a collectible.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// The additional deposit per byte of metadata and attributes.
+		#[pallet::constant]
+		type DepositPerByte: Get<BalanceOf<Self>>;
+
+		/// The pallet id, used to derive the account that holds wrapped external NFTs.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Identifier of a collection of external NFTs.
+		type NftCollectionId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier of an external NFT within its collection.
+		type NftId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The pallet holding external NFTs that can be wrapped and rented out, e.g.
+		/// pallet-uniques.
+		type Nfts: NftInspect<
+				Self::AccountId,
+				CollectionId = Self::NftCollectionId,
+				ItemId = Self::NftId,
+			> + NftTransfer<Self::AccountId>;
+
+		/// The maximum number of pricing tiers of a collectible.
+		#[pallet::constant]
+		type MaxPricingTiers: Get<u32>;
+
+		/// Computes the rent of rental periods, e.g. `LinearPricing`.
+		type RentalPricing: RentalPricing<Self>;
+
+		/// The number of most recent blocks over which the utilization of a collectible with
+		/// dynamic pricing is measured. Must not be zero.
+		#[pallet::constant]
+		type UtilizationWindow: Get<u32>;
+
+		/// The maximum number of past rental periods kept per collectible with dynamic pricing.
+		#[pallet::constant]
+		type MaxRentalHistory: Get<u32>;
+
+		/// The number of blocks between a lessor scheduling a price escalation and the escalation
+		/// taking effect, during which the lessee can opt out of renewing the rental.
+		#[pallet::constant]
+		type EscalationNoticePeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of rental offers that can expire in the same block.
+		#[pallet::constant]
+		type MaxOffersPerBlock: Get<u32>;
+
+		/// The maximum number of rental auctions that can end in the same block.
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+
+		/// The maximum number of reservations in the rental calendar of a collectible.
+		#[pallet::constant]
+		type MaxReservations: Get<u32>;
+
+		/// Handler for rentals starting, being renewed and ending. Its weight is not accounted
+		/// for, so implementations should be light.
+		type OnRentalLifecycle: OnRentalLifecycle<Self::AccountId, CollectibleId, Self::BlockNumber>;
+
+		/// Helper to create external NFT ids in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::NftCollectionId, Self::NftId>;
+
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
-	type CollectibleId = [u8; 16];
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper<NftCollectionId, NftId> {
+		fn collection(i: u16) -> NftCollectionId;
+		fn item(i: u16) -> NftId;
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<NftCollectionId: From<u16>, NftId: From<u16>> BenchmarkHelper<NftCollectionId, NftId> for () {
+		fn collection(i: u16) -> NftCollectionId {
+			i.into()
+		}
+
+		fn item(i: u16) -> NftId {
+			i.into()
+		}
+	}
+
+	pub type CollectibleId = [u8; 16];
+
+	pub type AgreementId = u64;
 
-	type BalanceOf<T> =
+	pub type CollectionId = u32;
+
+	pub type CollectionPolicyOf<T> = CollectionPolicy<BalanceOf<T>>;
+
+	pub type BalanceOf<T> =
 		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
+	pub type AttributeKeyOf<T> = BoundedVec<u8, <T as Config>::KeyLimit>;
+
+	pub type AttributeValueOf<T> = BoundedVec<u8, <T as Config>::ValueLimit>;
+
+	pub type PricingTiersOf<T> = BoundedVec<PricingTier, <T as Config>::MaxPricingTiers>;
+
+	pub type DynamicPricingOf<T> = DynamicPricing<BalanceOf<T>>;
+
+	pub type EscalationOf<T> = Escalation<BalanceOf<T>>;
+
+	pub type ReservationsOf<T> = BoundedVec<Reservation<T>, <T as Config>::MaxReservations>;
+
+	pub type RentalHistoryOf<T> = BoundedVec<
+		(<T as frame_system::Config>::BlockNumber, <T as frame_system::Config>::BlockNumber),
+		<T as Config>::MaxRentalHistory,
+	>;
+
 	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
 	pub struct Collectible<T: Config> {
 		// Unsigned integers of 16 bytes to represent a unique identifier
 		pub collectible_id: CollectibleId,
-		// `None` assumes not for sale
+		// `None` assumes not for rent
 		pub price_per_block: Option<BalanceOf<T>>,
 		pub lessor: T::AccountId,
 		pub lessee: Option<T::AccountId>,
 		pub rentable: bool,
 		pub minimum_rental_period: Option<u32>,
 		pub maximum_rental_period: Option<u32>,
+		// Security deposit reserved from the lessee for the duration of a rental
+		pub deposit: Option<BalanceOf<T>>,
+		// The collection whose policy applies to the collectible
+		pub collection: Option<CollectionId>,
 	}
 
 	/// Maps the Collectible struct to the collectible_id.
@@ -81,9 +260,298 @@ pub mod pallet {
 	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
 	pub struct RentalPeriodConfig<T: Config> {
+		pub agreement_id: AgreementId,
 		pub rental_periodic_interval: u32,
 		pub next_rent_block: T::BlockNumber,
 		pub recurring: bool,
+		pub status: RentalStatus<T::BlockNumber>,
+	}
+
+	/// Whether the rent of the current rental period was paid.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RentalStatus<BlockNumber> {
+		/// The rent of the current rental period was paid.
+		Active,
+		/// The rent of the rental period starting in block `since` could not be collected. The
+		/// rental is terminated in `next_rent_block` unless the lessee pays it before.
+		PaymentOverdue { since: BlockNumber },
+	}
+
+	/// The terms and history of a rental, from the moment it starts until it ends.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RentalAgreement<T: Config> {
+		pub collectible_id: CollectibleId,
+		pub lessor: T::AccountId,
+		pub lessee: T::AccountId,
+		/// Block in which the rental started.
+		pub start_block: T::BlockNumber,
+		/// Block in which the current rental period started.
+		pub period_start: T::BlockNumber,
+		/// Block in which the current rental period ends.
+		pub period_end: T::BlockNumber,
+		/// Everything the lessee paid under this agreement so far.
+		pub total_paid: BalanceOf<T>,
+		/// Rent paid for the current rental period, including its extensions.
+		pub period_rent: BalanceOf<T>,
+		/// Price per block of the collectible the current rental period was priced at.
+		pub price_per_block: BalanceOf<T>,
+		/// Number of times the rental was renewed.
+		pub renewals: u32,
+		/// How the rent is paid to the lessor.
+		pub payment_mode: PaymentMode,
+		/// Rent reserved on the lessee that was not released to the lessor yet.
+		pub escrowed: BalanceOf<T>,
+		/// Block up to which the escrowed rent was released to the lessor.
+		pub settled_until: T::BlockNumber,
+	}
+
+	/// Why a pending rental could not be processed.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RentalProcessingError {
+		/// The lessee has no rental configuration for the collectible.
+		MissingRentalConfig,
+		/// The rental was rescheduled to another block.
+		StaleSchedule,
+		/// The collectible no longer exists.
+		MissingCollectible,
+		/// The collectible is rented by another account.
+		LesseeMismatch,
+		/// The collectible has no price to renew the rental with.
+		MissingPrice,
+		/// No block with room for the next rental period was found.
+		NoAvailableRentalSlot,
+		/// The rent of the next rental period could not be computed.
+		PricingFailed,
+		/// The next rental period would overlap a reservation of the collectible.
+		ReservationConflict,
+	}
+
+	/// How much of the unused rent a lessee gets back when ending a rental early.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum RefundPolicy {
+		/// Nothing is refunded.
+		NoRefund,
+		/// The rent of the unused blocks is refunded in full.
+		ProRata,
+		/// The given share of the rent of the unused blocks is refunded.
+		Partial(Permill),
+	}
+
+	impl Default for RefundPolicy {
+		fn default() -> Self {
+			RefundPolicy::NoRefund
+		}
+	}
+
+	/// How the rent of a rental period is paid to the lessor.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PaymentMode {
+		/// The rent is paid to the lessor when the rental period starts.
+		Upfront,
+		/// The rent is reserved on the lessee and released to the lessor as the rental period
+		/// elapses.
+		Escrow,
+	}
+
+	impl Default for PaymentMode {
+		fn default() -> Self {
+			PaymentMode::Upfront
+		}
+	}
+
+	/// A discount on the rent of rental periods of at least `minimum_blocks` blocks.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PricingTier {
+		pub minimum_blocks: u32,
+		pub discount: Permill,
+	}
+
+	/// How the price per block of a collectible with dynamic pricing follows its utilization.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum PricingCurve {
+		/// The price rises in proportion to the utilization.
+		Linear,
+		/// The price rises with the square of the utilization, so it stays close to the floor
+		/// until the collectible is rented most of the time.
+		Quadratic,
+	}
+
+	/// The bounds and curve of the price per block of a collectible that follows its
+	/// utilization.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct DynamicPricing<Balance> {
+		/// The price per block of a collectible that was not rented recently.
+		pub floor: Balance,
+		/// The price per block of a collectible that was rented all the time recently.
+		pub ceiling: Balance,
+		pub curve: PricingCurve,
+	}
+
+	impl<Balance: AtLeast32BitUnsigned + Copy> DynamicPricing<Balance> {
+		/// The price per block at the given share of recent blocks the collectible was rented.
+		pub fn price_at(&self, utilization: Permill) -> Balance {
+			let share = match self.curve {
+				PricingCurve::Linear => utilization,
+				PricingCurve::Quadratic => utilization.square(),
+			};
+
+			let range = self.ceiling.saturating_sub(self.floor);
+			self.floor.saturating_add(share.mul_floor(range))
+		}
+	}
+
+	/// How the price per block of a rented collectible rises on the renewals of the rental.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum Escalation<Balance> {
+		/// The price per block rises by the given share on every renewal.
+		PerRenewal(Permill),
+		/// The price per block is changed to the given price from the next renewal on.
+		NewPrice(Balance),
+	}
+
+	/// A price escalation of a rental, applying to the renewals from `effective_from` on.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct ScheduledEscalation<T: Config> {
+		pub escalation: EscalationOf<T>,
+		pub effective_from: T::BlockNumber,
+	}
+
+	/// Terms a prospective lessee offers for renting a collectible, whose rent is reserved on
+	/// them until the offer is accepted, rejected, withdrawn or expires.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RentalOffer<T: Config> {
+		pub price_per_block: BalanceOf<T>,
+		/// The length of the rental period.
+		pub blocks: u32,
+		/// The block in which the offer expires.
+		pub expiry: T::BlockNumber,
+		/// The rent reserved on the lessee.
+		pub reserved: BalanceOf<T>,
+	}
+
+	/// An English auction for the next rental period of a collectible. Bids are prices per block,
+	/// and the rent of the highest bid is reserved on the bidder until they are outbid or the
+	/// auction is settled.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct RentalAuction<T: Config> {
+		/// The length of the rental period.
+		pub blocks: u32,
+		/// The lowest price per block that can be bid.
+		pub reserve_price: BalanceOf<T>,
+		/// The amount a bid must exceed the highest bid by.
+		pub bid_increment: BalanceOf<T>,
+		/// The block in which the auction is settled.
+		pub end: T::BlockNumber,
+		/// The highest bidder and their price per block.
+		pub highest_bid: Option<(T::AccountId, BalanceOf<T>)>,
+	}
+
+	/// A future rental period of a collectible booked by a lessee, whose rent is reserved on them
+	/// until the rental starts in block `start` or the reservation is cancelled.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Reservation<T: Config> {
+		pub lessee: T::AccountId,
+		/// The block in which the rental starts.
+		pub start: T::BlockNumber,
+		/// The block in which the rental ends.
+		pub end: T::BlockNumber,
+		/// The price per block of the collectible when it was reserved.
+		pub price_per_block: BalanceOf<T>,
+		/// The rent reserved on the lessee.
+		pub prepaid: BalanceOf<T>,
+		/// The pricing tier the rent was quoted with.
+		pub tier: Option<PricingTier>,
+		/// The last block in which the reservation can be cancelled for a refund.
+		pub refundable_until: T::BlockNumber,
+	}
+
+	/// A claim of the lessor on the security deposit of a rental.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct DepositClaim<T: Config> {
+		/// The part of the deposit claimed by the lessor.
+		pub amount: BalanceOf<T>,
+		/// The last block in which the lessee can dispute the claim.
+		pub disputable_until: T::BlockNumber,
+		/// Whether the lessee disputed the claim.
+		pub disputed: bool,
+	}
+
+	/// The security deposit reserved from the lessee of a rental.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SecurityDeposit<T: Config> {
+		pub collectible_id: CollectibleId,
+		pub lessor: T::AccountId,
+		pub lessee: T::AccountId,
+		/// The part of the deposit that is still reserved on the lessee.
+		pub amount: BalanceOf<T>,
+		/// Whether the rental ended while a claim on the deposit was open.
+		pub rental_ended: bool,
+		pub claim: Option<DepositClaim<T>>,
+	}
+
+	/// A fixed price offer to sell a collectible.
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct SaleListing<T: Config> {
+		/// The price the buyer pays to the lessor.
+		pub price: BalanceOf<T>,
+		/// Whether the collectible can be bought while rented, in which case the rental
+		/// continues with the buyer as lessor. Otherwise it can only be bought once the rental
+		/// ends.
+		pub subject_to_lease: bool,
+	}
+
+	/// Rules the creator of a collection sets for renting out its items.
+	#[derive(Clone, Encode, Decode, Eq, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct CollectionPolicy<Balance> {
+		/// Whether items of the collection can be rented out at all.
+		pub rentable: bool,
+		pub minimum_price_per_block: Option<Balance>,
+		pub maximum_price_per_block: Option<Balance>,
+		/// The shortest rental period items can be rented out for.
+		pub minimum_rental_period: Option<u32>,
+		/// The longest rental period items can be rented out for.
+		pub maximum_rental_period: Option<u32>,
+		/// The share of all rent paid for items of the collection that goes to its creator.
+		pub royalty: Permill,
+	}
+
+	impl<Balance: PartialOrd + Copy> CollectionPolicy<Balance> {
+		pub fn allows_price(&self, price_per_block: Balance) -> bool {
+			self.minimum_price_per_block.map_or(true, |minimum| price_per_block >= minimum) &&
+				self.maximum_price_per_block.map_or(true, |maximum| price_per_block <= maximum)
+		}
+
+		pub fn allows_rental_period(&self, blocks: u32) -> bool {
+			self.minimum_rental_period.map_or(true, |minimum| blocks >= minimum) &&
+				self.maximum_rental_period.map_or(true, |maximum| blocks <= maximum)
+		}
+	}
+
+	#[derive(Clone, Encode, Decode, PartialEq, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct Collection<T: Config> {
+		pub creator: T::AccountId,
+		pub policy: CollectionPolicyOf<T>,
+	}
+
+	/// Metadata and attributes attached to a collectible.
+	#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	pub struct CollectibleMetadata<T: Config> {
+		/// Opaque metadata, e.g. a URI or the hash of an off-chain document.
+		pub data: BoundedVec<u8, T::MetadataLimit>,
+		pub attributes: BoundedVec<(AttributeKeyOf<T>, AttributeValueOf<T>), T::MaxAttributes>,
+		/// The account the storage deposit is reserved from.
+		pub depositor: T::AccountId,
+		pub deposit: BalanceOf<T>,
 	}
 
 	/// Maps the account id to the collectibles rented and the rental configuration.
@@ -97,6 +565,147 @@ pub mod pallet {
 		RentalPeriodConfig<T>,
 	>;
 
+	/// Maps the agreement id to the ongoing rental agreement.
+	#[pallet::storage]
+	pub(super) type RentalAgreements<T: Config> =
+		StorageMap<_, Twox64Concat, AgreementId, RentalAgreement<T>>;
+
+	/// The id of the next rental agreement.
+	#[pallet::storage]
+	pub(super) type NextAgreementId<T: Config> = StorageValue<_, AgreementId, ValueQuery>;
+
+	/// Maps the collectible id to the refund policy set by its lessor.
+	#[pallet::storage]
+	pub(super) type RefundPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, RefundPolicy, ValueQuery>;
+
+	/// Maps the lessor and lessee to the refunds of ended rentals the lessor could not pay yet.
+	#[pallet::storage]
+	pub(super) type OwedRefunds<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// Maps the collectible id to the payment mode set by its lessor.
+	#[pallet::storage]
+	pub(super) type PaymentModes<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, PaymentMode, ValueQuery>;
+
+	/// Maps the collectible id to the pricing tiers set by its lessor, sorted by minimum blocks.
+	#[pallet::storage]
+	pub(super) type PricingTiers<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, PricingTiersOf<T>, ValueQuery>;
+
+	/// Maps the collectible id to the dynamic pricing set by its lessor.
+	#[pallet::storage]
+	pub(super) type DynamicPrices<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, DynamicPricingOf<T>>;
+
+	/// Maps the collectible id of a collectible with dynamic pricing to the start and end blocks
+	/// of its recent rental periods, oldest first.
+	#[pallet::storage]
+	pub(super) type RentalHistory<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, RentalHistoryOf<T>>;
+
+	/// Maps the agreement id to the price escalation scheduled by the lessor.
+	#[pallet::storage]
+	pub(super) type Escalations<T: Config> =
+		StorageMap<_, Twox64Concat, AgreementId, ScheduledEscalation<T>>;
+
+	/// Maps the collectible id and the account that made it to a rental offer.
+	#[pallet::storage]
+	pub(super) type RentalOffers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectibleId,
+		Twox64Concat,
+		T::AccountId,
+		RentalOffer<T>,
+	>;
+
+	/// Maps the block number to the rental offers expiring in it.
+	#[pallet::storage]
+	pub(super) type OfferExpiries<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<(CollectibleId, T::AccountId), T::MaxOffersPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Maps the collectible id to the auction for its next rental period.
+	#[pallet::storage]
+	pub(super) type RentalAuctions<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, RentalAuction<T>>;
+
+	/// Maps the block number to the collectibles whose auctions end in it.
+	#[pallet::storage]
+	pub(super) type AuctionEnds<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<CollectibleId, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Maps the collectible id to its reservations, sorted by start block. A reservation ends
+	/// before the next one starts.
+	#[pallet::storage]
+	pub(super) type Reservations<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, ReservationsOf<T>, ValueQuery>;
+
+	/// Maps the collectible id to the number of blocks before the start of a reservation from
+	/// which on it is no longer refunded when cancelled, set by its lessor.
+	#[pallet::storage]
+	pub(super) type ReservationCutoffs<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, T::BlockNumber, ValueQuery>;
+
+	/// Maps the agreement id to the security deposit of the rental. A deposit outlives its
+	/// agreement while a claim on it is open.
+	#[pallet::storage]
+	pub(super) type Deposits<T: Config> =
+		StorageMap<_, Twox64Concat, AgreementId, SecurityDeposit<T>>;
+
+	/// Maps the collectible id to its sale listing.
+	#[pallet::storage]
+	pub(super) type SaleListings<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, SaleListing<T>>;
+
+	/// Maps the collection id to the collection.
+	#[pallet::storage]
+	pub(super) type Collections<T: Config> =
+		StorageMap<_, Twox64Concat, CollectionId, Collection<T>>;
+
+	/// The id of the next collection.
+	#[pallet::storage]
+	pub(super) type NextCollectionId<T: Config> = StorageValue<_, CollectionId, ValueQuery>;
+
+	/// Maps the collectible id to its metadata and attributes.
+	#[pallet::storage]
+	pub(super) type MetadataOf<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, CollectibleMetadata<T>>;
+
+	/// Maps the collectible id to the external NFT it stands for.
+	#[pallet::storage]
+	pub(super) type WrappedNfts<T: Config> =
+		StorageMap<_, Twox64Concat, CollectibleId, (T::NftCollectionId, T::NftId)>;
+
+	/// Maps a wrapped external NFT to the collectible that stands for it.
+	#[pallet::storage]
+	pub(super) type NftCollectibles<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::NftCollectionId,
+		Blake2_128Concat,
+		T::NftId,
+		CollectibleId,
+	>;
+
 	/// List of rentable collectibles.
 	#[pallet::storage]
 	pub(super) type RentableCollectibles<T: Config> =
@@ -112,6 +721,10 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The oldest block whose pending rentals have not been fully processed yet.
+	#[pallet::storage]
+	pub(super) type NextPendingRentalsBlock<T: Config> = StorageValue<_, T::BlockNumber>;
+
 	#[pallet::storage]
 	pub(super) type AccountEquips<T: Config> =
 		StorageMap<_, Twox64Concat, T::AccountId, BoundedVec<CollectibleId, T::MaximumOwned>>;
@@ -129,25 +742,52 @@ pub mod pallet {
 		TransferSucceeded { from: T::AccountId, to: T::AccountId, collectible: CollectibleId },
 		/// The price of a collectible was successfully set.
 		RentMadeAvailable { collectible: CollectibleId, price_per_block: BalanceOf<T> },
-		/// A collectible was successfully rented.
+		/// A rental agreement was made.
+		RentalAgreementCreated {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			start_block: T::BlockNumber,
+			price_per_block: BalanceOf<T>,
+		},
+		/// The rent of a rental period was paid, to the lessor or into escrow depending on the
+		/// payment mode.
 		RentPayed {
+			agreement: AgreementId,
 			lessor: T::AccountId,
 			lessee: T::AccountId,
 			collectible: CollectibleId,
 			total_rent_price: BalanceOf<T>,
+			/// The pricing tier the `RentalPricing` applied to the rental period.
+			tier: Option<PricingTier>,
 		},
 		/// A rental period was successfully added.
-		RentalPeriodAdded { collectible: CollectibleId, next_rent_block: T::BlockNumber },
+		RentalPeriodAdded {
+			agreement: AgreementId,
+			collectible: CollectibleId,
+			next_rent_block: T::BlockNumber,
+		},
 		/// A rental period was successfully added.
-		RentalPeriodRemoved { collectible: CollectibleId, at_block: T::BlockNumber },
+		RentalPeriodRemoved {
+			agreement: AgreementId,
+			collectible: CollectibleId,
+			at_block: T::BlockNumber,
+		},
 		/// A rental period was successfully ended.
-		RentalEnded { lessor: T::AccountId, lessee: T::AccountId, collectible: CollectibleId },
+		RentalEnded {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+		},
 		/// Collectible rent made unavailable.
 		RentMadeUnavailable { collectible: CollectibleId },
 		/// Collectible rent made recurring.
-		RentalSetRecurring { collectible: CollectibleId, recurring: bool },
+		RentalSetRecurring { agreement: AgreementId, collectible: CollectibleId, recurring: bool },
 		/// Cancelled recurring rental since payment was not made.
 		ErrorTransferingRent {
+			agreement: AgreementId,
 			lessor: T::AccountId,
 			lessee: T::AccountId,
 			collectible: CollectibleId,
@@ -158,35 +798,266 @@ pub mod pallet {
 		CollectibleUnequipped { account: T::AccountId, collectible: CollectibleId },
 		/// Rental extended.
 		RentalExtended {
+			agreement: AgreementId,
 			lessor: T::AccountId,
 			lessee: T::AccountId,
 			collectible: CollectibleId,
 			next_rent_block: T::BlockNumber,
 		},
-	}
-
-	#[pallet::error]
-	pub enum Error<T> {
-		/// Each collectible must have a unique identifier
-		DuplicateCollectible,
-		/// The collectible doesn't exist
-		NoCollectible,
-		/// No collectible has no lessee
-		NoLessee,
-		/// You are not the lessor of this collectible.
-		NotLessor,
-		/// You are not the lessee of this collectible.
-		NotLessee,
-		/// You are already the lessee of this collectible
-		AlreadyRented,
-		/// The accounds can't exceed the maximum number of collectibles.
-		TooManyCollectibles,
-		/// The collectible is not available for rent.
-		RentNotAvailable,
-		/// The lessor of the collectible cannot rent it.
-		CannotRentOwnCollectible,
-		/// The rentale period is too short.
-		RentalPeriodTooShort,
+		/// The refund policy of a collectible was set.
+		RefundPolicySet { collectible: CollectibleId, policy: RefundPolicy },
+		/// Unused rent was refunded to the lessee of a rental that ended early.
+		RentRefunded {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+		},
+		/// The lessor could not pay the refund of a rental that ended early, which the lessee can
+		/// claim later.
+		RefundOwed {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+		},
+		/// A refund the lessor owed was paid to the lessee.
+		OwedRefundPaid { lessor: T::AccountId, lessee: T::AccountId, amount: BalanceOf<T> },
+		/// The payment mode of a collectible was set.
+		PaymentModeSet { collectible: CollectibleId, mode: PaymentMode },
+		/// The pricing tiers of a collectible were set.
+		PricingTiersSet { collectible: CollectibleId, tiers: PricingTiersOf<T> },
+		/// The dynamic pricing of a collectible was set or, if `None`, removed.
+		DynamicPricingSet { collectible: CollectibleId, pricing: Option<DynamicPricingOf<T>> },
+		/// The price per block of a collectible with dynamic pricing was adjusted to its
+		/// utilization.
+		PriceAdjusted {
+			collectible: CollectibleId,
+			utilization: Permill,
+			price_per_block: BalanceOf<T>,
+		},
+		/// The lessor scheduled a price escalation for the renewals of a rental. The lessee can
+		/// opt out by not renewing the rental before `effective_from`.
+		EscalationScheduled {
+			agreement: AgreementId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			escalation: EscalationOf<T>,
+			effective_from: T::BlockNumber,
+		},
+		/// The scheduled price escalation of a rental was cancelled.
+		EscalationCancelled { agreement: AgreementId, collectible: CollectibleId },
+		/// A price escalation took effect on the renewal of a rental.
+		PriceEscalated {
+			agreement: AgreementId,
+			collectible: CollectibleId,
+			price_per_block: BalanceOf<T>,
+		},
+		/// An offer to rent a collectible was made.
+		RentalOfferMade {
+			collectible: CollectibleId,
+			lessee: T::AccountId,
+			price_per_block: BalanceOf<T>,
+			blocks: u32,
+			expiry: T::BlockNumber,
+		},
+		/// The lessor accepted an offer to rent a collectible.
+		RentalOfferAccepted {
+			agreement: AgreementId,
+			collectible: CollectibleId,
+			lessee: T::AccountId,
+		},
+		/// The lessor rejected an offer to rent a collectible.
+		RentalOfferRejected { collectible: CollectibleId, lessee: T::AccountId },
+		/// An offer to rent a collectible was withdrawn.
+		RentalOfferWithdrawn { collectible: CollectibleId, lessee: T::AccountId },
+		/// An offer to rent a collectible expired.
+		RentalOfferExpired { collectible: CollectibleId, lessee: T::AccountId },
+		/// An auction for the next rental period of a collectible was opened.
+		AuctionOpened {
+			collectible: CollectibleId,
+			blocks: u32,
+			reserve_price: BalanceOf<T>,
+			bid_increment: BalanceOf<T>,
+			end: T::BlockNumber,
+		},
+		/// A bid was placed in the auction for a collectible.
+		BidPlaced {
+			collectible: CollectibleId,
+			bidder: T::AccountId,
+			price_per_block: BalanceOf<T>,
+		},
+		/// The auction for a collectible was cancelled.
+		AuctionCancelled { collectible: CollectibleId },
+		/// The auction for a collectible ended and it was rented out to the highest bidder.
+		AuctionSettled {
+			agreement: AgreementId,
+			collectible: CollectibleId,
+			lessee: T::AccountId,
+			price_per_block: BalanceOf<T>,
+		},
+		/// The auction for a collectible ended without bids.
+		AuctionEndedWithoutBids { collectible: CollectibleId },
+		/// The collectible of an auction could not be rented out to the highest bidder, whose bid
+		/// was returned.
+		AuctionSettlementFailed { collectible: CollectibleId, bidder: T::AccountId },
+		/// A future rental period of a collectible was reserved.
+		RentalReserved {
+			collectible: CollectibleId,
+			lessee: T::AccountId,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+			prepaid: BalanceOf<T>,
+		},
+		/// A reservation was cancelled. Its rent was returned to the lessee if it was refundable,
+		/// and paid to the lessor otherwise.
+		ReservationCancelled {
+			collectible: CollectibleId,
+			lessee: T::AccountId,
+			start: T::BlockNumber,
+			refunded: bool,
+		},
+		/// A reservation started and the collectible was rented out to its lessee.
+		ReservationActivated {
+			agreement: AgreementId,
+			collectible: CollectibleId,
+			lessee: T::AccountId,
+		},
+		/// The collectible of a reservation could not be rented out to its lessee, whose rent was
+		/// returned.
+		ReservationActivationFailed { collectible: CollectibleId, lessee: T::AccountId },
+		/// The lessor set how long before its start a reservation is no longer refunded.
+		ReservationCutoffSet { collectible: CollectibleId, cutoff: T::BlockNumber },
+		/// The rent of a rental period was reserved on the lessee.
+		RentEscrowed {
+			agreement: AgreementId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+		},
+		/// Escrowed rent was released to the lessor.
+		RentReleased {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+		},
+		/// The rent of a recurring rental could not be collected and is due by `deadline`.
+		RentPaymentOverdue {
+			agreement: AgreementId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+			deadline: T::BlockNumber,
+		},
+		/// The overdue rent of a rental was paid.
+		OverdueRentPaid {
+			agreement: AgreementId,
+			lessee: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+		},
+		/// A security deposit was reserved from the lessee.
+		DepositReserved { agreement: AgreementId, lessee: T::AccountId, amount: BalanceOf<T> },
+		/// A security deposit was returned to the lessee.
+		DepositReleased { agreement: AgreementId, lessee: T::AccountId, amount: BalanceOf<T> },
+		/// The lessor claimed part of a security deposit.
+		DepositClaimed {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			amount: BalanceOf<T>,
+			disputable_until: T::BlockNumber,
+		},
+		/// The lessee disputed the claim on their security deposit.
+		DepositClaimDisputed { agreement: AgreementId, lessee: T::AccountId },
+		/// Part of a security deposit was paid to the lessor.
+		DepositSlashed {
+			agreement: AgreementId,
+			lessor: T::AccountId,
+			lessee: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// A collectible was listed for sale.
+		CollectibleListedForSale {
+			collectible: CollectibleId,
+			price: BalanceOf<T>,
+			subject_to_lease: bool,
+		},
+		/// The sale listing of a collectible was removed.
+		SaleListingRemoved { collectible: CollectibleId },
+		/// A collectible was sold.
+		CollectibleSold {
+			collectible: CollectibleId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		},
+		/// The metadata of a collectible was set.
+		MetadataSet { collectible: CollectibleId, data: BoundedVec<u8, T::MetadataLimit> },
+		/// The metadata and attributes of a collectible were cleared.
+		MetadataCleared { collectible: CollectibleId },
+		/// An attribute of a collectible was set.
+		AttributeSet {
+			collectible: CollectibleId,
+			key: AttributeKeyOf<T>,
+			value: AttributeValueOf<T>,
+		},
+		/// An attribute of a collectible was cleared.
+		AttributeCleared { collectible: CollectibleId, key: AttributeKeyOf<T> },
+		/// A collection was created.
+		CollectionCreated { collection: CollectionId, creator: T::AccountId },
+		/// The rental policy of a collection was set.
+		CollectionPolicySet { collection: CollectionId, policy: CollectionPolicyOf<T> },
+		/// Part of the rent of a collectible was paid to the creator of its collection.
+		RoyaltyPaid {
+			agreement: AgreementId,
+			collection: CollectionId,
+			creator: T::AccountId,
+			collectible: CollectibleId,
+			amount: BalanceOf<T>,
+		},
+		/// An external NFT was taken into custody and a collectible standing for it was minted.
+		NftWrapped { collectible: CollectibleId, collection: T::NftCollectionId, item: T::NftId },
+		/// An external NFT was returned to the lessor of the burned collectible standing for it.
+		NftUnwrapped {
+			collectible: CollectibleId,
+			collection: T::NftCollectionId,
+			item: T::NftId,
+			lessor: T::AccountId,
+		},
+		/// A pending rental could not be processed and was cleaned up.
+		RentalProcessingFailed {
+			agreement: Option<AgreementId>,
+			collectible: CollectibleId,
+			reason: RentalProcessingError,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Each collectible must have a unique identifier
+		DuplicateCollectible,
+		/// The collectible doesn't exist
+		NoCollectible,
+		/// No collectible has no lessee
+		NoLessee,
+		/// You are not the lessor of this collectible.
+		NotLessor,
+		/// You are not the lessee of this collectible.
+		NotLessee,
+		/// You are already the lessee of this collectible
+		AlreadyRented,
+		/// The accounds can't exceed the maximum number of collectibles.
+		TooManyCollectibles,
+		/// The collectible is not available for rent.
+		RentNotAvailable,
+		/// The lessor of the collectible cannot rent it.
+		CannotRentOwnCollectible,
+		/// The rentale period is too short.
+		RentalPeriodTooShort,
 		/// The rentale period is too long.
 		RentalPeriodTooLong,
 		/// Lessor cannot perform operation while collectible is rented.
@@ -201,23 +1072,120 @@ pub mod pallet {
 		MinimumMustBeLessThanMaximum,
 		/// No account found associated with collectible.
 		NoAccountFoundForCollectible,
+		/// No block with room for the rental was found within the scheduling probes.
+		NoAvailableRentalSlot,
+		/// An arithmetic operation overflowed.
+		Overflow,
+		/// The rent of the rental is not escrowed.
+		NoEscrowedRent,
+		/// The rental has no security deposit.
+		NoDeposit,
+		/// The rental has no overdue rent.
+		NoPaymentOverdue,
+		/// The rent of the rental is overdue.
+		PaymentOverdue,
+		/// The security deposit already has an open claim.
+		DepositClaimPending,
+		/// The security deposit has no open claim.
+		NoDepositClaim,
+		/// The claim exceeds the security deposit.
+		ClaimExceedsDeposit,
+		/// The claim can no longer be disputed.
+		DisputePeriodOver,
+		/// The claim can still be disputed.
+		DisputePeriodNotOver,
+		/// The claim was disputed and has to be resolved by the dispute origin.
+		ClaimDisputed,
+		/// The claim was not disputed.
+		ClaimNotDisputed,
+		/// The collectible cannot be transferred to its lessor.
+		TransferToSelf,
+		/// The collectible is not for sale.
+		NotForSale,
+		/// The lessor of the collectible cannot buy it.
+		CannotBuyOwnCollectible,
+		/// The sale price is higher than the buyer is willing to pay.
+		PriceTooHigh,
+		/// The collectible has no metadata.
+		NoMetadata,
+		/// The collectible has no attribute with the given key.
+		NoAttribute,
+		/// The collectible reached the maximum number of attributes.
+		TooManyAttributes,
+		/// The collection doesn't exist.
+		NoCollection,
+		/// You are not the creator of this collection.
+		NotCollectionCreator,
+		/// The policy of the collection does not allow renting out its items.
+		CollectionNotRentable,
+		/// The price per block is not allowed by the policy of the collection.
+		PriceOutsideCollectionPolicy,
+		/// The rental period is not allowed by the policy of the collection.
+		RentalPeriodOutsideCollectionPolicy,
+		/// The collection already exists.
+		CollectionExists,
+		/// The attribute key or value is longer than allowed.
+		AttributeTooLong,
+		/// You are not the owner of this NFT.
+		NotNftOwner,
+		/// Pricing tiers must be sorted by strictly increasing minimum blocks.
+		PricingTiersNotSorted,
+		/// The price of the collectible follows its utilization.
+		DynamicallyPriced,
+		/// The rental has no scheduled price escalation.
+		NoEscalation,
+		/// The account has no offer to rent the collectible.
+		NoOffer,
+		/// The expiry of the offer must be in the future.
+		OfferExpiryInPast,
+		/// No more offers can expire in the given block.
+		TooManyOffers,
+		/// The collectible is being auctioned.
+		AuctionInProgress,
+		/// The collectible is not being auctioned.
+		NoAuction,
+		/// The end of the auction must be in the future.
+		AuctionEndInPast,
+		/// The auction has ended.
+		AuctionEnded,
+		/// The bid is below the reserve price or does not exceed the highest bid by the bid
+		/// increment.
+		BidTooLow,
+		/// The auction already has bids.
+		AuctionHasBids,
+		/// No more auctions can end in the given block.
+		TooManyAuctions,
+		/// The start of the reservation must be in the future.
+		ReservationStartInPast,
+		/// The rental period overlaps a rental or reservation of the collectible.
+		ReservationConflict,
+		/// The collectible reached the maximum number of reservations.
+		TooManyReservations,
+		/// The account has no reservation of the collectible starting in the given block.
+		NoReservation,
+		/// The lessor cannot pay the refund of the unused rent.
+		LessorCannotRefund,
+		/// The lessor owes the account no refund.
+		NoRefundOwed,
+		/// The collection id must be given to create a collection.
+		CollectionRequired,
 	}
 
 	// Pallet callable functions
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::mint(T::MaximumOwned::get()))]
 		#[pallet::call_index(0)]
 		pub fn mint(origin: OriginFor<T>) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 			let collectible_gen_collectible_id = Self::gen_collectible_id();
 
-			Self::do_mint(&sender, collectible_gen_collectible_id)?;
+			Self::do_mint(&sender, collectible_gen_collectible_id, None)?;
 
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::burn(T::MaximumOwned::get(), T::MaxReservations::get()))]
 		#[pallet::call_index(1)]
 		pub fn burn(origin: OriginFor<T>, collectible_id: CollectibleId) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
@@ -227,18 +1195,12 @@ pub mod pallet {
 			Self::ensure_user_is_lessor(&sender, &collectible)?;
 			ensure!(collectible.lessee == None, Error::<T>::NotAllowedWhileRented);
 
-			Collectibles::<T>::remove(&collectible_id);
-
-			let mut lessor_collectibles = LessorCollectibles::<T>::get(&sender).unwrap_or_default();
-			lessor_collectibles.retain(|&x| x != collectible_id);
-			LessorCollectibles::<T>::insert(&sender, lessor_collectibles);
-
-			Self::unequip_collectible_from_account(sender.clone(), collectible.collectible_id);
+			Self::do_burn(collectible)?;
 
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::set_rentable(T::MaximumOwned::get()))]
 		#[pallet::call_index(2)]
 		pub fn set_rentable(
 			origin: OriginFor<T>,
@@ -246,6 +1208,7 @@ pub mod pallet {
 			price_per_block: BalanceOf<T>,
 			minimum_rental_period: u32,
 			maximum_rental_period: u32,
+			deposit: Option<BalanceOf<T>>,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
@@ -257,10 +1220,24 @@ pub mod pallet {
 			);
 			ensure!(collectible.lessee == None, Error::<T>::NotAllowedWhileRented);
 
+			if let Some(policy) = Self::collection_policy(&collectible) {
+				ensure!(policy.rentable, Error::<T>::CollectionNotRentable);
+				ensure!(
+					policy.allows_price(price_per_block),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+				ensure!(
+					policy.allows_rental_period(minimum_rental_period) &&
+						policy.allows_rental_period(maximum_rental_period),
+					Error::<T>::RentalPeriodOutsideCollectionPolicy
+				);
+			}
+
 			collectible.price_per_block = Some(price_per_block);
 			collectible.rentable = true;
 			collectible.minimum_rental_period = Some(minimum_rental_period);
 			collectible.maximum_rental_period = Some(maximum_rental_period);
+			collectible.deposit = deposit;
 
 			Collectibles::<T>::insert(&collectible_id, &collectible);
 
@@ -281,7 +1258,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::rent())]
 		#[pallet::call_index(3)]
 		pub fn rent(
 			origin: OriginFor<T>,
@@ -293,6 +1270,8 @@ pub mod pallet {
 
 			let collectible = Self::fetch_collectible(collectible_id)?;
 
+			ensure!(blocks > 0, Error::<T>::RentalPeriodTooShort);
+
 			if let Some(minimum_rental_period) = collectible.minimum_rental_period {
 				ensure!(blocks >= minimum_rental_period, Error::<T>::RentalPeriodTooShort);
 			}
@@ -304,12 +1283,29 @@ pub mod pallet {
 			ensure!(collectible.lessor != sender, Error::<T>::CannotRentOwnCollectible);
 			ensure!(collectible.lessee.is_none(), Error::<T>::RentNotAvailable);
 			ensure!(collectible.lessee != Some(sender.clone()), Error::<T>::AlreadyRented);
+			ensure!(
+				!RentalAuctions::<T>::contains_key(&collectible_id),
+				Error::<T>::AuctionInProgress
+			);
+
+			// The policy may have changed since the collectible was made rentable
+			if let Some(policy) = Self::collection_policy(&collectible) {
+				ensure!(policy.rentable, Error::<T>::CollectionNotRentable);
+				ensure!(
+					collectible.price_per_block.map_or(true, |price| policy.allows_price(price)),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+				ensure!(
+					policy.allows_rental_period(blocks),
+					Error::<T>::RentalPeriodOutsideCollectionPolicy
+				);
+			}
 
-			Self::do_rent_collectible(collectible_id, sender, blocks, recurring)?;
+			Self::do_rent_collectible(collectible_id, sender, blocks, recurring, None, None)?;
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::set_unrentable(T::MaximumOwned::get()))]
 		#[pallet::call_index(4)]
 		pub fn set_unrentable(
 			origin: OriginFor<T>,
@@ -332,7 +1328,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::set_recurring())]
 		#[pallet::call_index(5)]
 		pub fn set_recurring(
 			origin: OriginFor<T>,
@@ -352,11 +1348,12 @@ pub mod pallet {
 
 			lessee_rental.recurring = recurring;
 
-			LesseeCollectibles::<T>::insert(sender, &collectible_id, lessee_rental);
+			LesseeCollectibles::<T>::insert(sender, &collectible_id, &lessee_rental);
 
 			Collectibles::<T>::insert(&collectible_id, collectible);
 
 			Self::deposit_event(Event::RentalSetRecurring {
+				agreement: lessee_rental.agreement_id,
 				collectible: collectible_id,
 				recurring,
 			});
@@ -364,7 +1361,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::extend_rent())]
 		#[pallet::call_index(6)]
 		pub fn extend_rent(
 			origin: OriginFor<T>,
@@ -381,7 +1378,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::equip_collectible(T::MaximumOwned::get()))]
 		#[pallet::call_index(7)]
 		pub fn equip_collectible(
 			origin: OriginFor<T>,
@@ -416,7 +1413,7 @@ pub mod pallet {
 			Ok(())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::unequip_collectible(T::MaximumOwned::get()))]
 		#[pallet::call_index(8)]
 		pub fn unequip_collectible(
 			origin: OriginFor<T>,
@@ -438,228 +1435,2326 @@ pub mod pallet {
 
 			Ok(())
 		}
-	}
 
-	// Pallet internal functions
-	impl<T: Config> Pallet<T> {
-		// Function to mint a collectible
-		pub fn do_mint(
-			lessor: &T::AccountId,
+		#[pallet::weight(T::WeightInfo::set_refund_policy())]
+		#[pallet::call_index(9)]
+		pub fn set_refund_policy(
+			origin: OriginFor<T>,
 			collectible_id: CollectibleId,
-		) -> Result<CollectibleId, DispatchError> {
-			let collectible = Collectible::<T> {
-				collectible_id,
-				price_per_block: None,
-				lessor: lessor.clone(),
-				lessee: None,
-				rentable: false,
-				minimum_rental_period: None,
-				maximum_rental_period: None,
-			};
+			policy: RefundPolicy,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-			ensure!(
-				!Collectibles::<T>::contains_key(&collectible.collectible_id),
-				Error::<T>::DuplicateCollectible
-			);
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
 
-			Collectibles::<T>::insert(collectible.collectible_id, &collectible);
+			RefundPolicies::<T>::insert(&collectible_id, policy);
 
-			let mut lessor_collectibles = LessorCollectibles::<T>::get(&lessor).unwrap_or_default();
-			lessor_collectibles
-				.try_push(collectible.collectible_id)
-				.map_err(|_| Error::<T>::TooManyCollectiblesOwned)?;
-			LessorCollectibles::<T>::insert(&lessor, lessor_collectibles);
+			Self::deposit_event(Event::RefundPolicySet { collectible: collectible_id, policy });
 
-			Self::deposit_event(Event::CollectibleCreated {
-				collectible: collectible_id,
-				lessor: lessor.clone(),
-				current_lessee: None,
-			});
+			Ok(())
+		}
 
-			Ok(collectible_id)
+		#[pallet::weight(T::WeightInfo::end_rental())]
+		#[pallet::call_index(10)]
+		pub fn end_rental(origin: OriginFor<T>, collectible_id: CollectibleId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessee(&sender, &collectible)?;
+
+			Self::do_end_rental(collectible, sender)?;
+
+			Ok(())
 		}
 
-		fn do_rent_collectible(
+		#[pallet::weight(T::WeightInfo::set_payment_mode())]
+		#[pallet::call_index(11)]
+		pub fn set_payment_mode(
+			origin: OriginFor<T>,
 			collectible_id: CollectibleId,
-			lessee: T::AccountId,
-			rent_periodic_interval: u32,
-			recurring: bool,
+			mode: PaymentMode,
 		) -> DispatchResult {
-			let mut collectible = Self::fetch_collectible(collectible_id)?;
+			let sender = ensure_signed(origin)?;
 
-			let lessor = &collectible.lessor;
-			let lessee = lessee.clone();
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
 
-			let total_rent_price =
-				collectible.price_per_block.unwrap() * rent_periodic_interval.into();
+			PaymentModes::<T>::insert(&collectible_id, mode);
 
-			Self::transfer_funds(&lessee, &lessor, total_rent_price)?;
+			Self::deposit_event(Event::PaymentModeSet { collectible: collectible_id, mode });
 
-			Self::deposit_event(Event::RentPayed {
-				lessee: lessee.clone(),
-				lessor: lessor.clone(),
-				collectible: collectible_id,
-				total_rent_price,
-			});
+			Ok(())
+		}
 
-			collectible.lessee = Some(lessee.clone());
+		#[pallet::weight(T::WeightInfo::claim_rent())]
+		#[pallet::call_index(12)]
+		pub fn claim_rent(origin: OriginFor<T>, collectible_id: CollectibleId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-			let next_rent_block = Self::append_pending_rental_to_available_block(
-				None,
-				rent_periodic_interval,
-				collectible_id,
-				&lessee,
-			)
-			.unwrap();
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
 
-			let rental_config = RentalPeriodConfig {
-				rental_periodic_interval: rent_periodic_interval.into(),
-				next_rent_block,
-				recurring,
-			};
+			let lessee = collectible.lessee.ok_or(Error::<T>::NoLessee)?;
+			let lessee_rental = LesseeCollectibles::<T>::get(&lessee, &collectible_id)
+				.ok_or(Error::<T>::NoCollectible)?;
+			let agreement_id = lessee_rental.agreement_id;
 
-			// overwrite rental configuration for the collectible
-			LesseeCollectibles::<T>::insert(&lessee, &collectible_id, &rental_config);
+			let mut agreement =
+				RentalAgreements::<T>::get(agreement_id).ok_or(Error::<T>::NoCollectible)?;
+			ensure!(agreement.payment_mode == PaymentMode::Escrow, Error::<T>::NoEscrowedRent);
 
-			Collectibles::<T>::insert(&collectible_id, &collectible);
+			Self::release_elapsed_rent(
+				agreement_id,
+				&mut agreement,
+				frame_system::Pallet::<T>::block_number(),
+			);
+			RentalAgreements::<T>::insert(agreement_id, agreement);
 
 			Ok(())
 		}
 
-		fn do_extend_rent(collectible: Collectible<T>, blocks: T::BlockNumber) -> DispatchResult {
-			let lessee = collectible.lessee.as_ref().unwrap();
+		#[pallet::weight(T::WeightInfo::claim_deposit())]
+		#[pallet::call_index(13)]
+		pub fn claim_deposit(
+			origin: OriginFor<T>,
+			agreement_id: AgreementId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
-			let lessee_rental = LesseeCollectibles::<T>::get(&lessee, &collectible.collectible_id)
-				.ok_or(Error::<T>::NoCollectible)?;
+			let mut deposit = Deposits::<T>::get(agreement_id).ok_or(Error::<T>::NoDeposit)?;
+			ensure!(deposit.lessor == sender, Error::<T>::NotLessor);
+			ensure!(deposit.claim.is_none(), Error::<T>::DepositClaimPending);
+			ensure!(amount <= deposit.amount, Error::<T>::ClaimExceedsDeposit);
+
+			let disputable_until =
+				frame_system::Pallet::<T>::block_number() + T::DepositDisputePeriod::get();
+			deposit.claim = Some(DepositClaim { amount, disputable_until, disputed: false });
+			Deposits::<T>::insert(agreement_id, deposit);
+
+			Self::deposit_event(Event::DepositClaimed {
+				agreement: agreement_id,
+				lessor: sender,
+				amount,
+				disputable_until,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::dispute_deposit_claim())]
+		#[pallet::call_index(14)]
+		pub fn dispute_deposit_claim(
+			origin: OriginFor<T>,
+			agreement_id: AgreementId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
 
+			let mut deposit = Deposits::<T>::get(agreement_id).ok_or(Error::<T>::NoDeposit)?;
+			ensure!(deposit.lessee == sender, Error::<T>::NotLessee);
+
+			let claim = deposit.claim.as_mut().ok_or(Error::<T>::NoDepositClaim)?;
 			ensure!(
-				blocks + lessee_rental.next_rent_block <=
-					collectible.maximum_rental_period.unwrap().into(),
-				Error::<T>::RentalPeriodTooLong
+				frame_system::Pallet::<T>::block_number() <= claim.disputable_until,
+				Error::<T>::DisputePeriodOver
 			);
 
-			// add blocks and rental period interval
-			let total_rent_price = convert_to_primitive::<T::BlockNumber, u32>(blocks).unwrap() *
-				convert_to_primitive::<BalanceOf<T>, u32>(collectible.price_per_block.unwrap())
-					.unwrap();
-
-			Self::transfer_funds(&lessee, &collectible.lessor, total_rent_price.into())?;
+			claim.disputed = true;
+			Deposits::<T>::insert(agreement_id, deposit);
 
-			Self::deposit_event(Event::RentPayed {
-				lessee: lessee.clone(),
-				lessor: collectible.lessor.clone(),
-				collectible: collectible.collectible_id,
-				total_rent_price: total_rent_price.into(),
+			Self::deposit_event(Event::DepositClaimDisputed {
+				agreement: agreement_id,
+				lessee: sender,
 			});
 
-			let next_rent_block = lessee_rental.next_rent_block;
+			Ok(())
+		}
 
-			// Remove old rental from pending rentals since we are extending it
-			let mut pending_rental = PendingRentals::<T>::get(&next_rent_block);
-			pending_rental.retain(|(id, _)| *id != collectible.collectible_id);
-			PendingRentals::<T>::insert(&next_rent_block, &pending_rental);
+		/// Pays an undisputed claim to the lessor once it can no longer be disputed. Can be
+		/// called by anyone.
+		#[pallet::weight(T::WeightInfo::settle_deposit_claim())]
+		#[pallet::call_index(15)]
+		pub fn settle_deposit_claim(
+			origin: OriginFor<T>,
+			agreement_id: AgreementId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
 
-			Self::deposit_event(Event::RentalPeriodRemoved {
-				collectible: collectible.collectible_id,
-				at_block: next_rent_block,
-			});
+			let mut deposit = Deposits::<T>::get(agreement_id).ok_or(Error::<T>::NoDeposit)?;
+			let claim = deposit.claim.clone().ok_or(Error::<T>::NoDepositClaim)?;
+			ensure!(!claim.disputed, Error::<T>::ClaimDisputed);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > claim.disputable_until,
+				Error::<T>::DisputePeriodNotOver
+			);
 
-			// Add new rental to pending rentals to extend the rent
-			let next_rent_block = Self::append_pending_rental_to_available_block(
-				Some(next_rent_block),
-				convert_to_primitive::<T::BlockNumber, u32>(blocks).unwrap(),
-				collectible.collectible_id,
-				&lessee,
-			)?;
+			Self::slash_deposit(agreement_id, &mut deposit, claim.amount);
+			Self::close_deposit_claim(agreement_id, deposit);
 
-			// overwrite rental configuration for the collectible
-			let rental_config = RentalPeriodConfig { next_rent_block, ..lessee_rental };
-			LesseeCollectibles::<T>::insert(lessee, &collectible.collectible_id, &rental_config);
+			Ok(())
+		}
+
+		/// Settles a disputed claim by paying `amount` of the deposit to the lessor.
+		#[pallet::weight(T::WeightInfo::resolve_deposit_dispute())]
+		#[pallet::call_index(16)]
+		pub fn resolve_deposit_dispute(
+			origin: OriginFor<T>,
+			agreement_id: AgreementId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::DisputeOrigin::ensure_origin(origin)?;
+
+			let mut deposit = Deposits::<T>::get(agreement_id).ok_or(Error::<T>::NoDeposit)?;
+			let claim = deposit.claim.clone().ok_or(Error::<T>::NoDepositClaim)?;
+			ensure!(claim.disputed, Error::<T>::ClaimNotDisputed);
+			ensure!(amount <= claim.amount, Error::<T>::ClaimExceedsDeposit);
+
+			Self::slash_deposit(agreement_id, &mut deposit, amount);
+			Self::close_deposit_claim(agreement_id, deposit);
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::pay_overdue())]
+		#[pallet::call_index(17)]
+		pub fn pay_overdue(origin: OriginFor<T>, collectible_id: CollectibleId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessee(&sender, &collectible)?;
+
+			Self::do_pay_overdue(collectible, sender)?;
+
+			Ok(())
+		}
+
+		/// Transfers a collectible to another lessor. A rented collectible can only be
+		/// transferred `subject_to_lease`, in which case the rental continues and the rent from
+		/// then on goes to the new lessor.
+		#[pallet::weight(T::WeightInfo::transfer(T::MaximumOwned::get()))]
+		#[pallet::call_index(18)]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			to: T::AccountId,
+			subject_to_lease: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(sender != to, Error::<T>::TransferToSelf);
+
+			if let Some(lessee) = &collectible.lessee {
+				ensure!(subject_to_lease, Error::<T>::NotAllowedWhileRented);
+				ensure!(*lessee != to, Error::<T>::CannotRentOwnCollectible);
+			}
+
+			Self::do_transfer(collectible, to)?;
+
+			Ok(())
+		}
+
+		/// Lists a collectible for sale at a fixed price, replacing any previous listing.
+		#[pallet::weight(T::WeightInfo::list_for_sale())]
+		#[pallet::call_index(19)]
+		pub fn list_for_sale(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			price: BalanceOf<T>,
+			subject_to_lease: bool,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+
+			SaleListings::<T>::insert(&collectible_id, SaleListing { price, subject_to_lease });
+
+			Self::deposit_event(Event::CollectibleListedForSale {
+				collectible: collectible_id,
+				price,
+				subject_to_lease,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::unlist_from_sale())]
+		#[pallet::call_index(20)]
+		pub fn unlist_from_sale(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(SaleListings::<T>::contains_key(&collectible_id), Error::<T>::NotForSale);
+
+			SaleListings::<T>::remove(&collectible_id);
+
+			Self::deposit_event(Event::SaleListingRemoved { collectible: collectible_id });
+
+			Ok(())
+		}
+
+		/// Buys a listed collectible. Fails if the price is higher than `max_price`, so that the
+		/// buyer is not caught by a price change.
+		#[pallet::weight(T::WeightInfo::buy(T::MaximumOwned::get()))]
+		#[pallet::call_index(21)]
+		pub fn buy(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			let listing = SaleListings::<T>::get(&collectible_id).ok_or(Error::<T>::NotForSale)?;
+			ensure!(collectible.lessor != sender, Error::<T>::CannotBuyOwnCollectible);
+			ensure!(listing.price <= max_price, Error::<T>::PriceTooHigh);
+
+			if let Some(lessee) = &collectible.lessee {
+				ensure!(listing.subject_to_lease, Error::<T>::NotAllowedWhileRented);
+				ensure!(*lessee != sender, Error::<T>::CannotRentOwnCollectible);
+			}
+
+			Self::do_buy(collectible, sender, listing.price)?;
+
+			Ok(())
+		}
+
+		/// Sets the metadata of a collectible, reserving a deposit for its size from the lessor.
+		#[pallet::weight(T::WeightInfo::set_metadata())]
+		#[pallet::call_index(22)]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			data: BoundedVec<u8, T::MetadataLimit>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_metadata_editable(&sender, &collectible)?;
+
+			let mut metadata = Self::metadata_or_default(collectible_id, &sender);
+			metadata.data = data.clone();
+			Self::store_metadata(&sender, collectible_id, metadata)?;
+
+			Self::deposit_event(Event::MetadataSet { collectible: collectible_id, data });
+
+			Ok(())
+		}
+
+		/// Clears the metadata and attributes of a collectible and returns the deposit.
+		#[pallet::weight(T::WeightInfo::clear_metadata())]
+		#[pallet::call_index(23)]
+		pub fn clear_metadata(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_metadata_editable(&sender, &collectible)?;
+
+			let metadata = MetadataOf::<T>::take(&collectible_id).ok_or(Error::<T>::NoMetadata)?;
+			T::Currency::unreserve(&metadata.depositor, metadata.deposit);
+
+			Self::deposit_event(Event::MetadataCleared { collectible: collectible_id });
+
+			Ok(())
+		}
+
+		/// Sets an attribute of a collectible, overwriting the value of an existing key.
+		#[pallet::weight(T::WeightInfo::set_attribute())]
+		#[pallet::call_index(24)]
+		pub fn set_attribute(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			key: AttributeKeyOf<T>,
+			value: AttributeValueOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_metadata_editable(&sender, &collectible)?;
+
+			let mut metadata = Self::metadata_or_default(collectible_id, &sender);
+			match metadata.attributes.iter_mut().find(|(k, _)| *k == key) {
+				Some((_, v)) => *v = value.clone(),
+				None => metadata
+					.attributes
+					.try_push((key.clone(), value.clone()))
+					.map_err(|_| Error::<T>::TooManyAttributes)?,
+			}
+			Self::store_metadata(&sender, collectible_id, metadata)?;
+
+			Self::deposit_event(Event::AttributeSet { collectible: collectible_id, key, value });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::clear_attribute())]
+		#[pallet::call_index(25)]
+		pub fn clear_attribute(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			key: AttributeKeyOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_metadata_editable(&sender, &collectible)?;
+
+			let mut metadata =
+				MetadataOf::<T>::get(&collectible_id).ok_or(Error::<T>::NoAttribute)?;
+			let attributes_len = metadata.attributes.len();
+			metadata.attributes.retain(|(k, _)| *k != key);
+			ensure!(metadata.attributes.len() < attributes_len, Error::<T>::NoAttribute);
+			Self::store_metadata(&sender, collectible_id, metadata)?;
+
+			Self::deposit_event(Event::AttributeCleared { collectible: collectible_id, key });
+
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::create_collection())]
+		#[pallet::call_index(26)]
+		pub fn create_collection(
+			origin: OriginFor<T>,
+			policy: CollectionPolicyOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::ensure_valid_policy(&policy)?;
+
+			let collection_id = NextCollectionId::<T>::get();
+			let next_collection_id = collection_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+			NextCollectionId::<T>::put(next_collection_id);
+
+			Collections::<T>::insert(collection_id, Collection { creator: sender.clone(), policy });
+
+			Self::deposit_event(Event::CollectionCreated {
+				collection: collection_id,
+				creator: sender,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the rental policy of a collection. Ongoing rentals are not affected.
+		#[pallet::weight(T::WeightInfo::set_collection_policy())]
+		#[pallet::call_index(27)]
+		pub fn set_collection_policy(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			policy: CollectionPolicyOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let mut collection =
+				Collections::<T>::get(collection_id).ok_or(Error::<T>::NoCollection)?;
+			ensure!(collection.creator == sender, Error::<T>::NotCollectionCreator);
+			Self::ensure_valid_policy(&policy)?;
+
+			collection.policy = policy;
+			Collections::<T>::insert(collection_id, &collection);
+
+			Self::deposit_event(Event::CollectionPolicySet { collection: collection_id, policy });
+
+			Ok(())
+		}
+
+		/// Mints a new collectible into a collection. Only the creator of the collection can
+		/// mint into it.
+		#[pallet::weight(T::WeightInfo::mint_into_collection(T::MaximumOwned::get()))]
+		#[pallet::call_index(28)]
+		pub fn mint_into_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collection =
+				Collections::<T>::get(collection_id).ok_or(Error::<T>::NoCollection)?;
+			ensure!(collection.creator == sender, Error::<T>::NotCollectionCreator);
+
+			let collectible_gen_collectible_id = Self::gen_collectible_id();
+			Self::do_mint(&sender, collectible_gen_collectible_id, Some(collection_id))?;
+
+			Ok(())
+		}
+
+		/// Sets discounts on the rent of longer rental periods. The tier with the highest
+		/// `minimum_blocks` that a rental period reaches applies to it.
+		#[pallet::weight(T::WeightInfo::set_pricing_tiers())]
+		#[pallet::call_index(30)]
+		pub fn set_pricing_tiers(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			tiers: PricingTiersOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+			ensure!(
+				tiers.windows(2).all(|pair| pair[0].minimum_blocks < pair[1].minimum_blocks),
+				Error::<T>::PricingTiersNotSorted
+			);
+
+			PricingTiers::<T>::insert(&collectible_id, &tiers);
+
+			Self::deposit_event(Event::PricingTiersSet { collectible: collectible_id, tiers });
+
+			Ok(())
+		}
+
+		/// Takes an external NFT into the custody of the pallet and mints a collectible standing
+		/// for it, which can be rented out like any other. Burning the collectible returns the
+		/// NFT to its lessor.
+		#[pallet::weight(T::WeightInfo::wrap_nft(T::MaximumOwned::get()))]
+		#[pallet::call_index(29)]
+		pub fn wrap_nft(
+			origin: OriginFor<T>,
+			collection: T::NftCollectionId,
+			item: T::NftId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(
+				T::Nfts::owner(&collection, &item) == Some(sender.clone()),
+				Error::<T>::NotNftOwner
+			);
+
+			T::Nfts::transfer(&collection, &item, &Self::account_id())?;
+
+			let collectible_id = Self::gen_collectible_id();
+			Self::do_mint(&sender, collectible_id, None)?;
+
+			WrappedNfts::<T>::insert(&collectible_id, (collection, item));
+			NftCollectibles::<T>::insert(collection, item, collectible_id);
+
+			Self::deposit_event(Event::NftWrapped {
+				collectible: collectible_id,
+				collection,
+				item,
+			});
+
+			Ok(())
+		}
+
+		/// Makes the price per block of a collectible follow its utilization. Rentals are priced
+		/// between `floor` and `ceiling` along `curve`, according to the share of the last
+		/// `UtilizationWindow` blocks the collectible was rented as of the block they are priced
+		/// in. Passing `None` keeps the current price per block from then on.
+		#[pallet::weight(T::WeightInfo::set_dynamic_pricing())]
+		#[pallet::call_index(31)]
+		pub fn set_dynamic_pricing(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			pricing: Option<DynamicPricingOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+
+			match pricing {
+				Some(pricing) => {
+					ensure!(
+						pricing.floor <= pricing.ceiling,
+						Error::<T>::MinimumMustBeLessThanMaximum
+					);
+
+					if let Some(policy) = Self::collection_policy(&collectible) {
+						ensure!(
+							policy.allows_price(pricing.floor) &&
+								policy.allows_price(pricing.ceiling),
+							Error::<T>::PriceOutsideCollectionPolicy
+						);
+					}
+
+					DynamicPrices::<T>::insert(&collectible_id, pricing);
+				},
+				None => {
+					DynamicPrices::<T>::remove(&collectible_id);
+					RentalHistory::<T>::remove(&collectible_id);
+				},
+			}
+
+			Self::deposit_event(Event::DynamicPricingSet { collectible: collectible_id, pricing });
+
+			Self::adjust_dynamic_price(collectible_id);
+
+			Ok(())
+		}
+
+		/// Schedules a price escalation for the renewals of the current rental of a collectible,
+		/// taking effect `EscalationNoticePeriod` blocks from now. The lessee can opt out with
+		/// `set_recurring` until then. Passing `None` cancels the scheduled escalation.
+		#[pallet::weight(T::WeightInfo::schedule_escalation())]
+		#[pallet::call_index(32)]
+		pub fn schedule_escalation(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			escalation: Option<EscalationOf<T>>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			let lessee = collectible.lessee.clone().ok_or(Error::<T>::NoLessee)?;
+			let agreement_id = LesseeCollectibles::<T>::get(&lessee, &collectible_id)
+				.ok_or(Error::<T>::NoCollectible)?
+				.agreement_id;
+
+			let escalation = match escalation {
+				Some(escalation) => escalation,
+				None => {
+					ensure!(Escalations::<T>::contains_key(agreement_id), Error::<T>::NoEscalation);
+					Escalations::<T>::remove(agreement_id);
+
+					Self::deposit_event(Event::EscalationCancelled {
+						agreement: agreement_id,
+						collectible: collectible_id,
+					});
+
+					return Ok(())
+				},
+			};
+
+			ensure!(
+				!DynamicPrices::<T>::contains_key(&collectible_id),
+				Error::<T>::DynamicallyPriced
+			);
+
+			if let (Escalation::NewPrice(price_per_block), Some(policy)) =
+				(escalation, Self::collection_policy(&collectible))
+			{
+				ensure!(
+					policy.allows_price(price_per_block),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+			}
+
+			let effective_from =
+				frame_system::Pallet::<T>::block_number() + T::EscalationNoticePeriod::get();
+			Escalations::<T>::insert(
+				agreement_id,
+				ScheduledEscalation { escalation, effective_from },
+			);
+
+			Self::deposit_event(Event::EscalationScheduled {
+				agreement: agreement_id,
+				lessee,
+				collectible: collectible_id,
+				escalation,
+				effective_from,
+			});
+
+			Ok(())
+		}
+
+		/// Offers to rent a collectible for `blocks` blocks at `price_per_block`, whether it is
+		/// rentable or not. The rent is reserved until the lessor accepts or rejects the offer, it
+		/// is withdrawn or it expires in block `expiry`. An earlier offer of the same account for
+		/// the collectible is replaced.
+		#[pallet::weight(T::WeightInfo::make_rental_offer())]
+		#[pallet::call_index(33)]
+		pub fn make_rental_offer(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			price_per_block: BalanceOf<T>,
+			blocks: u32,
+			expiry: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			ensure!(collectible.lessor != sender, Error::<T>::CannotRentOwnCollectible);
+			ensure!(blocks > 0, Error::<T>::RentalPeriodTooShort);
+			ensure!(
+				expiry > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::OfferExpiryInPast
+			);
+
+			if RentalOffers::<T>::contains_key(&collectible_id, &sender) {
+				Self::remove_offer(collectible_id, &sender)?;
+			}
+
+			let reserved =
+				price_per_block.checked_mul(&blocks.into()).ok_or(Error::<T>::Overflow)?;
+			T::Currency::reserve(&sender, reserved).map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+			OfferExpiries::<T>::try_append(expiry, (collectible_id, sender.clone()))
+				.map_err(|_| Error::<T>::TooManyOffers)?;
+			RentalOffers::<T>::insert(
+				&collectible_id,
+				&sender,
+				RentalOffer { price_per_block, blocks, expiry, reserved },
+			);
+
+			Self::deposit_event(Event::RentalOfferMade {
+				collectible: collectible_id,
+				lessee: sender,
+				price_per_block,
+				blocks,
+				expiry,
+			});
+
+			Ok(())
+		}
+
+		/// Rents out a collectible to `lessee` on the terms of their offer. The rental is not
+		/// recurring, but the lessee can make it so.
+		#[pallet::weight(T::WeightInfo::accept_offer())]
+		#[pallet::call_index(34)]
+		pub fn accept_offer(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			lessee: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+			ensure!(
+				!RentalAuctions::<T>::contains_key(&collectible_id),
+				Error::<T>::AuctionInProgress
+			);
+
+			let offer = Self::remove_offer(collectible_id, &lessee)?;
+
+			if let Some(policy) = Self::collection_policy(&collectible) {
+				ensure!(policy.rentable, Error::<T>::CollectionNotRentable);
+				ensure!(
+					policy.allows_price(offer.price_per_block),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+				ensure!(
+					policy.allows_rental_period(offer.blocks),
+					Error::<T>::RentalPeriodOutsideCollectionPolicy
+				);
+			}
+
+			let agreement_id = Self::do_rent_collectible(
+				collectible_id,
+				lessee.clone(),
+				offer.blocks,
+				false,
+				Some(offer.price_per_block),
+				None,
+			)?;
+
+			Self::deposit_event(Event::RentalOfferAccepted {
+				agreement: agreement_id,
+				collectible: collectible_id,
+				lessee,
+			});
+
+			Ok(())
+		}
+
+		/// Rejects the offer of `lessee` to rent a collectible and returns the reserved rent.
+		#[pallet::weight(T::WeightInfo::reject_offer())]
+		#[pallet::call_index(35)]
+		pub fn reject_offer(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			lessee: T::AccountId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+
+			Self::remove_offer(collectible_id, &lessee)?;
+
+			Self::deposit_event(Event::RentalOfferRejected { collectible: collectible_id, lessee });
+
+			Ok(())
+		}
+
+		/// Withdraws an offer to rent a collectible and returns the reserved rent.
+		#[pallet::weight(T::WeightInfo::withdraw_offer())]
+		#[pallet::call_index(36)]
+		pub fn withdraw_offer(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::remove_offer(collectible_id, &sender)?;
+
+			Self::deposit_event(Event::RentalOfferWithdrawn {
+				collectible: collectible_id,
+				lessee: sender,
+			});
+
+			Ok(())
+		}
+
+		/// Auctions the next rental period of `blocks` blocks of a collectible that is not
+		/// rented. The collectible cannot be rented otherwise until the auction is settled in
+		/// block `end`, when it is rented out to the highest bidder.
+		#[pallet::weight(T::WeightInfo::open_auction())]
+		#[pallet::call_index(37)]
+		pub fn open_auction(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			blocks: u32,
+			reserve_price: BalanceOf<T>,
+			bid_increment: BalanceOf<T>,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+			ensure!(
+				!RentalAuctions::<T>::contains_key(&collectible_id),
+				Error::<T>::AuctionInProgress
+			);
+			ensure!(blocks > 0, Error::<T>::RentalPeriodTooShort);
+			ensure!(end > frame_system::Pallet::<T>::block_number(), Error::<T>::AuctionEndInPast);
+
+			if let Some(policy) = Self::collection_policy(&collectible) {
+				ensure!(policy.rentable, Error::<T>::CollectionNotRentable);
+				ensure!(
+					policy.allows_price(reserve_price),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+				ensure!(
+					policy.allows_rental_period(blocks),
+					Error::<T>::RentalPeriodOutsideCollectionPolicy
+				);
+			}
+
+			AuctionEnds::<T>::try_append(end, collectible_id)
+				.map_err(|_| Error::<T>::TooManyAuctions)?;
+			RentalAuctions::<T>::insert(
+				&collectible_id,
+				RentalAuction { blocks, reserve_price, bid_increment, end, highest_bid: None },
+			);
+
+			Self::deposit_event(Event::AuctionOpened {
+				collectible: collectible_id,
+				blocks,
+				reserve_price,
+				bid_increment,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Bids `price_per_block` in the auction for a collectible, reserving the rent of the
+		/// auctioned rental period. The reserved rent of the previous highest bidder is returned.
+		#[pallet::weight(T::WeightInfo::bid())]
+		#[pallet::call_index(38)]
+		pub fn bid(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			price_per_block: BalanceOf<T>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			let mut auction =
+				RentalAuctions::<T>::get(&collectible_id).ok_or(Error::<T>::NoAuction)?;
+			ensure!(collectible.lessor != sender, Error::<T>::CannotRentOwnCollectible);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < auction.end,
+				Error::<T>::AuctionEnded
+			);
+
+			let minimum_bid = match &auction.highest_bid {
+				Some((_, highest)) => highest.saturating_add(auction.bid_increment),
+				None => auction.reserve_price,
+			};
+			ensure!(price_per_block >= minimum_bid, Error::<T>::BidTooLow);
+
+			if let Some(policy) = Self::collection_policy(&collectible) {
+				ensure!(
+					policy.allows_price(price_per_block),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+			}
+
+			let blocks = auction.blocks.into();
+			if let Some((bidder, highest)) = &auction.highest_bid {
+				T::Currency::unreserve(bidder, highest.saturating_mul(blocks));
+			}
+
+			let reserved = price_per_block.checked_mul(&blocks).ok_or(Error::<T>::Overflow)?;
+			T::Currency::reserve(&sender, reserved).map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+			auction.highest_bid = Some((sender.clone(), price_per_block));
+			RentalAuctions::<T>::insert(&collectible_id, auction);
+
+			Self::deposit_event(Event::BidPlaced {
+				collectible: collectible_id,
+				bidder: sender,
+				price_per_block,
+			});
+
+			Ok(())
+		}
+
+		/// Cancels the auction for a collectible that has no bids yet.
+		#[pallet::weight(T::WeightInfo::cancel_auction())]
+		#[pallet::call_index(39)]
+		pub fn cancel_auction(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+			let auction = RentalAuctions::<T>::get(&collectible_id).ok_or(Error::<T>::NoAuction)?;
+			ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+
+			RentalAuctions::<T>::remove(&collectible_id);
+			AuctionEnds::<T>::mutate(auction.end, |auctions| {
+				auctions.retain(|id| *id != collectible_id)
+			});
+
+			Self::deposit_event(Event::AuctionCancelled { collectible: collectible_id });
+
+			Ok(())
+		}
+
+		/// Reserves a collectible from block `start` until block `end`. The rent is quoted and
+		/// reserved on the lessee now, and the collectible is rented out to them at its current
+		/// price per block when the reservation starts.
+		#[pallet::weight(T::WeightInfo::reserve())]
+		#[pallet::call_index(40)]
+		pub fn reserve(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::at_current_price(Self::fetch_collectible(collectible_id)?);
+			ensure!(collectible.rentable, Error::<T>::RentNotAvailable);
+			let price_per_block =
+				collectible.price_per_block.ok_or(Error::<T>::RentNotAvailable)?;
+			ensure!(collectible.lessor != sender, Error::<T>::CannotRentOwnCollectible);
+			ensure!(
+				!RentalAuctions::<T>::contains_key(&collectible_id),
+				Error::<T>::AuctionInProgress
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(start > now, Error::<T>::ReservationStartInPast);
+
+			let blocks = end.checked_sub(&start).ok_or(Error::<T>::RentalPeriodTooShort)?;
+			let blocks = convert_to_primitive::<T::BlockNumber, u32>(blocks)
+				.map_err(|_| Error::<T>::Overflow)?;
+			ensure!(blocks > 0, Error::<T>::RentalPeriodTooShort);
+
+			if let Some(minimum_rental_period) = collectible.minimum_rental_period {
+				ensure!(blocks >= minimum_rental_period, Error::<T>::RentalPeriodTooShort);
+			}
+
+			if let Some(maximum_rental_period) = collectible.maximum_rental_period {
+				ensure!(blocks <= maximum_rental_period, Error::<T>::RentalPeriodTooLong);
+			}
+
+			if let Some(policy) = Self::collection_policy(&collectible) {
+				ensure!(policy.rentable, Error::<T>::CollectionNotRentable);
+				ensure!(
+					policy.allows_price(price_per_block),
+					Error::<T>::PriceOutsideCollectionPolicy
+				);
+				ensure!(
+					policy.allows_rental_period(blocks),
+					Error::<T>::RentalPeriodOutsideCollectionPolicy
+				);
+			}
+
+			if let Some(lessee) = &collectible.lessee {
+				let rental_end = LesseeCollectibles::<T>::get(lessee, &collectible_id)
+					.map(|rental_config| rental_config.next_rent_block);
+				ensure!(
+					rental_end.map_or(true, |rental_end| rental_end < start),
+					Error::<T>::ReservationConflict
+				);
+			}
+
+			// Each reservation must end before the next one starts
+			let mut reservations = Reservations::<T>::get(&collectible_id);
+			let index = reservations
+				.iter()
+				.position(|reservation| reservation.start > start)
+				.unwrap_or(reservations.len());
+			ensure!(
+				index == 0 || reservations[index - 1].end < start,
+				Error::<T>::ReservationConflict
+			);
+			ensure!(
+				reservations.get(index).map_or(true, |next| end < next.start),
+				Error::<T>::ReservationConflict
+			);
+
+			let prepaid = T::RentalPricing::rent_price(&collectible, &sender, blocks, now)?;
+			let tier = T::RentalPricing::applied_tier(&collectible, blocks);
+			let refundable_until =
+				start.saturating_sub(ReservationCutoffs::<T>::get(&collectible_id));
+
+			reservations
+				.try_insert(
+					index,
+					Reservation {
+						lessee: sender.clone(),
+						start,
+						end,
+						price_per_block,
+						prepaid,
+						tier,
+						refundable_until,
+					},
+				)
+				.map_err(|_| Error::<T>::TooManyReservations)?;
+
+			// The rental is started by processing its pending rental in the start block
+			PendingRentals::<T>::try_append(start, (collectible_id, sender.clone()))
+				.map_err(|_| Error::<T>::NoAvailableRentalSlot)?;
+			T::Currency::reserve(&sender, prepaid).map_err(|_| Error::<T>::NotEnoughBalance)?;
+			Reservations::<T>::insert(&collectible_id, reservations);
+
+			Self::deposit_event(Event::RentalReserved {
+				collectible: collectible_id,
+				lessee: sender,
+				start,
+				end,
+				prepaid,
+			});
+
+			Ok(())
+		}
+
+		/// Cancels the reservation of a collectible starting in block `start`. The reserved rent
+		/// is returned if the reservation is cancelled before the cutoff of the collectible, and
+		/// paid to the lessor otherwise.
+		#[pallet::weight(T::WeightInfo::cancel_reservation())]
+		#[pallet::call_index(41)]
+		pub fn cancel_reservation(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			start: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+
+			let mut reservations = Reservations::<T>::get(&collectible_id);
+			let index = reservations
+				.iter()
+				.position(|reservation| reservation.start == start && reservation.lessee == sender)
+				.ok_or(Error::<T>::NoReservation)?;
+			let reservation = reservations.remove(index);
+			Reservations::<T>::insert(&collectible_id, reservations);
+
+			PendingRentals::<T>::mutate(start, |pending_rentals| {
+				pending_rentals.retain(|(id, account)| *id != collectible_id || *account != sender)
+			});
+
+			let refunded =
+				frame_system::Pallet::<T>::block_number() <= reservation.refundable_until;
+			if refunded {
+				T::Currency::unreserve(&sender, reservation.prepaid);
+			} else {
+				let not_paid = T::Currency::repatriate_reserved(
+					&sender,
+					&collectible.lessor,
+					reservation.prepaid,
+					BalanceStatus::Free,
+				)?;
+				ensure!(not_paid.is_zero(), Error::<T>::NotEnoughBalance);
+			}
+
+			Self::deposit_event(Event::ReservationCancelled {
+				collectible: collectible_id,
+				lessee: sender,
+				start,
+				refunded,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the number of blocks before the start of a reservation from which on it is no
+		/// longer refunded when cancelled. Only applies to new reservations.
+		#[pallet::weight(T::WeightInfo::set_reservation_cutoff())]
+		#[pallet::call_index(42)]
+		pub fn set_reservation_cutoff(
+			origin: OriginFor<T>,
+			collectible_id: CollectibleId,
+			cutoff: T::BlockNumber,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let collectible = Self::fetch_collectible(collectible_id)?;
+			Self::ensure_user_is_lessor(&sender, &collectible)?;
+
+			ReservationCutoffs::<T>::insert(&collectible_id, cutoff);
+
+			Self::deposit_event(Event::ReservationCutoffSet {
+				collectible: collectible_id,
+				cutoff,
+			});
+
+			Ok(())
+		}
+
+		/// Claims the refunds `lessor` owes the caller for rentals that ended early.
+		#[pallet::weight(T::WeightInfo::claim_refund())]
+		#[pallet::call_index(43)]
+		pub fn claim_refund(origin: OriginFor<T>, lessor: T::AccountId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			let amount = OwedRefunds::<T>::get(&lessor, &sender);
+			ensure!(!amount.is_zero(), Error::<T>::NoRefundOwed);
+
+			Self::transfer_funds(&lessor, &sender, amount)
+				.map_err(|_| Error::<T>::LessorCannotRefund)?;
+			OwedRefunds::<T>::remove(&lessor, &sender);
+
+			Self::deposit_event(Event::OwedRefundPaid { lessor, lessee: sender, amount });
+
+			Ok(())
+		}
+	}
+
+	// Pallet internal functions
+	impl<T: Config> Pallet<T> {
+		// Function to mint a collectible
+		pub fn do_mint(
+			lessor: &T::AccountId,
+			collectible_id: CollectibleId,
+			collection: Option<CollectionId>,
+		) -> Result<CollectibleId, DispatchError> {
+			let collectible = Collectible::<T> {
+				collectible_id,
+				price_per_block: None,
+				lessor: lessor.clone(),
+				lessee: None,
+				rentable: false,
+				minimum_rental_period: None,
+				maximum_rental_period: None,
+				deposit: None,
+				collection,
+			};
+
+			ensure!(
+				!Collectibles::<T>::contains_key(&collectible.collectible_id),
+				Error::<T>::DuplicateCollectible
+			);
+
+			Collectibles::<T>::insert(collectible.collectible_id, &collectible);
+
+			let mut lessor_collectibles = LessorCollectibles::<T>::get(&lessor).unwrap_or_default();
+			lessor_collectibles
+				.try_push(collectible.collectible_id)
+				.map_err(|_| Error::<T>::TooManyCollectiblesOwned)?;
+			LessorCollectibles::<T>::insert(&lessor, lessor_collectibles);
+
+			Self::deposit_event(Event::CollectibleCreated {
+				collectible: collectible_id,
+				lessor: lessor.clone(),
+				current_lessee: None,
+			});
+
+			Ok(collectible_id)
+		}
+
+		// Rents out a collectible at `offered_price` per block, or at its own price if `None`,
+		// and returns the id of the rental agreement. The rent of a rental that was paid in
+		// advance is `prepaid` along with the pricing tier it was quoted with. It is reserved on
+		// the lessee and settled as is.
+		fn do_rent_collectible(
+			collectible_id: CollectibleId,
+			lessee: T::AccountId,
+			rent_periodic_interval: u32,
+			recurring: bool,
+			offered_price: Option<BalanceOf<T>>,
+			prepaid: Option<(BalanceOf<T>, Option<PricingTier>)>,
+		) -> Result<AgreementId, DispatchError> {
+			let mut collectible = Self::at_current_price(Self::fetch_collectible(collectible_id)?);
+
+			let lessor = &collectible.lessor;
+			let lessee = lessee.clone();
+			let price_per_block = offered_price
+				.or(collectible.price_per_block)
+				.ok_or(Error::<T>::RentNotAvailable)?;
+
+			let agreement_id = Self::next_agreement_id();
+
+			// Prepaid rent is settled like any other, from the balance it was reserved from
+			let (total_rent_price, tier) = match prepaid {
+				Some((prepaid, tier)) => {
+					let not_unreserved = T::Currency::unreserve(&lessee, prepaid);
+					ensure!(not_unreserved.is_zero(), Error::<T>::NotEnoughBalance);
+					(prepaid, tier)
+				},
+				None => {
+					let priced = Collectible {
+						price_per_block: Some(price_per_block),
+						..collectible.clone()
+					};
+					let total_rent_price = T::RentalPricing::rent_price(
+						&priced,
+						&lessee,
+						rent_periodic_interval,
+						frame_system::Pallet::<T>::block_number(),
+					)?;
+					(
+						total_rent_price,
+						T::RentalPricing::applied_tier(&priced, rent_periodic_interval),
+					)
+				},
+			};
+			let payment_mode = PaymentModes::<T>::get(&collectible_id);
+
+			Self::collect_rent(
+				agreement_id,
+				payment_mode,
+				&lessee,
+				&lessor,
+				collectible_id,
+				total_rent_price,
+				tier,
+			)?;
+
+			let next_rent_block = Self::append_pending_rental_to_available_block(
+				None,
+				rent_periodic_interval,
+				collectible_id,
+				&lessee,
+				agreement_id,
+			)?;
+			ensure!(
+				Self::ends_before_reservations(collectible_id, next_rent_block),
+				Error::<T>::ReservationConflict
+			);
+
+			let start_block = frame_system::Pallet::<T>::block_number();
+
+			RentalAgreements::<T>::insert(
+				agreement_id,
+				RentalAgreement {
+					collectible_id,
+					lessor: lessor.clone(),
+					lessee: lessee.clone(),
+					start_block,
+					period_start: start_block,
+					period_end: next_rent_block,
+					total_paid: total_rent_price,
+					period_rent: total_rent_price,
+					price_per_block,
+					renewals: 0,
+					payment_mode,
+					escrowed: match payment_mode {
+						PaymentMode::Upfront => Zero::zero(),
+						PaymentMode::Escrow => total_rent_price,
+					},
+					settled_until: start_block,
+				},
+			);
+
+			Self::deposit_event(Event::RentalAgreementCreated {
+				agreement: agreement_id,
+				lessor: lessor.clone(),
+				lessee: lessee.clone(),
+				collectible: collectible_id,
+				start_block,
+				price_per_block,
+			});
+
+			if let Some(deposit) = collectible.deposit.filter(|deposit| !deposit.is_zero()) {
+				T::Currency::reserve(&lessee, deposit).map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+				Deposits::<T>::insert(
+					agreement_id,
+					SecurityDeposit {
+						collectible_id,
+						lessor: lessor.clone(),
+						lessee: lessee.clone(),
+						amount: deposit,
+						rental_ended: false,
+						claim: None,
+					},
+				);
+
+				Self::deposit_event(Event::DepositReserved {
+					agreement: agreement_id,
+					lessee: lessee.clone(),
+					amount: deposit,
+				});
+			}
+
+			let rental_config = RentalPeriodConfig {
+				agreement_id,
+				rental_periodic_interval: rent_periodic_interval.into(),
+				next_rent_block,
+				recurring,
+				status: RentalStatus::Active,
+			};
+
+			// overwrite rental configuration for the collectible
+			LesseeCollectibles::<T>::insert(&lessee, &collectible_id, &rental_config);
+
+			collectible.lessee = Some(lessee.clone());
+			Collectibles::<T>::insert(&collectible_id, &collectible);
+
+			T::OnRentalLifecycle::on_rental_started(&collectible_id, &lessee, &next_rent_block);
+
+			Self::record_rental_period(collectible_id, start_block, next_rent_block);
+			Self::adjust_dynamic_price(collectible_id);
+
+			Ok(agreement_id)
+		}
+
+		fn do_buy(
+			collectible: Collectible<T>,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+		) -> DispatchResult {
+			let collectible_id = collectible.collectible_id;
+			let seller = collectible.lessor.clone();
+
+			Self::transfer_funds(&buyer, &seller, price)?;
+			Self::do_transfer(collectible, buyer.clone())?;
+
+			Self::deposit_event(Event::CollectibleSold {
+				collectible: collectible_id,
+				seller,
+				buyer,
+				price,
+			});
+
+			Ok(())
+		}
+
+		pub(crate) fn do_burn(collectible: Collectible<T>) -> DispatchResult {
+			let collectible_id = collectible.collectible_id;
+			let lessor = collectible.lessor;
+			ensure!(
+				!RentalAuctions::<T>::contains_key(&collectible_id),
+				Error::<T>::AuctionInProgress
+			);
+
+			if let Some((collection, item)) = WrappedNfts::<T>::take(&collectible_id) {
+				T::Nfts::transfer(&collection, &item, &lessor)?;
+				NftCollectibles::<T>::remove(collection, item);
+
+				Self::deposit_event(Event::NftUnwrapped {
+					collectible: collectible_id,
+					collection,
+					item,
+					lessor: lessor.clone(),
+				});
+			}
+
+			Collectibles::<T>::remove(&collectible_id);
+
+			let mut lessor_collectibles = LessorCollectibles::<T>::get(&lessor).unwrap_or_default();
+			lessor_collectibles.retain(|&x| x != collectible_id);
+			LessorCollectibles::<T>::insert(&lessor, lessor_collectibles);
+
+			Self::unequip_collectible_from_account(lessor, collectible_id);
+
+			RefundPolicies::<T>::remove(&collectible_id);
+			PaymentModes::<T>::remove(&collectible_id);
+			PricingTiers::<T>::remove(&collectible_id);
+			DynamicPrices::<T>::remove(&collectible_id);
+			RentalHistory::<T>::remove(&collectible_id);
+			SaleListings::<T>::remove(&collectible_id);
+			ReservationCutoffs::<T>::remove(&collectible_id);
+
+			// The rent of reservations that will never start is returned
+			for reservation in Reservations::<T>::take(&collectible_id) {
+				T::Currency::unreserve(&reservation.lessee, reservation.prepaid);
+				PendingRentals::<T>::mutate(reservation.start, |pending_rentals| {
+					pending_rentals.retain(|(id, account)| {
+						*id != collectible_id || *account != reservation.lessee
+					})
+				});
+			}
+
+			if let Some(metadata) = MetadataOf::<T>::take(&collectible_id) {
+				T::Currency::unreserve(&metadata.depositor, metadata.deposit);
+			}
+
+			Ok(())
+		}
+
+		pub(crate) fn do_transfer(
+			mut collectible: Collectible<T>,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let collectible_id = collectible.collectible_id;
+			let from = collectible.lessor.clone();
+			ensure!(
+				!RentalAuctions::<T>::contains_key(&collectible_id),
+				Error::<T>::AuctionInProgress
+			);
+
+			let mut to_collectibles = LessorCollectibles::<T>::get(&to).unwrap_or_default();
+			to_collectibles
+				.try_push(collectible_id)
+				.map_err(|_| Error::<T>::TooManyCollectiblesOwned)?;
+			LessorCollectibles::<T>::insert(&to, to_collectibles);
+
+			let mut from_collectibles = LessorCollectibles::<T>::get(&from).unwrap_or_default();
+			from_collectibles.retain(|&x| x != collectible_id);
+			LessorCollectibles::<T>::insert(&from, from_collectibles);
+
+			Self::unequip_collectible_from_account(from.clone(), collectible_id);
+
+			let rental_config = collectible
+				.lessee
+				.as_ref()
+				.and_then(|lessee| LesseeCollectibles::<T>::get(lessee, &collectible_id));
+
+			if let Some(rental_config) = rental_config {
+				let agreement_id = rental_config.agreement_id;
+
+				RentalAgreements::<T>::mutate(agreement_id, |agreement| {
+					if let Some(agreement) = agreement {
+						// The escrowed rent of the blocks elapsed so far is due to the previous
+						// lessor
+						Self::release_elapsed_rent(
+							agreement_id,
+							agreement,
+							frame_system::Pallet::<T>::block_number(),
+						);
+						agreement.lessor = to.clone();
+					}
+				});
+
+				// An open claim stays with the lessor who made it
+				Deposits::<T>::mutate(agreement_id, |deposit| {
+					if let Some(deposit) = deposit {
+						if deposit.claim.is_none() {
+							deposit.lessor = to.clone();
+						}
+					}
+				});
+			}
+
+			collectible.lessor = to.clone();
+			Collectibles::<T>::insert(&collectible_id, &collectible);
+
+			// The listing was made by the previous lessor
+			SaleListings::<T>::remove(&collectible_id);
+
+			Self::deposit_event(Event::TransferSucceeded { from, to, collectible: collectible_id });
+
+			Ok(())
+		}
+
+		/// The account that holds the wrapped external NFTs.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		/// The current user of a wrapped external NFT, i.e. the lessee of the collectible standing
+		/// for it.
+		pub fn nft_user(collection: &T::NftCollectionId, item: &T::NftId) -> Option<T::AccountId> {
+			let collectible_id = NftCollectibles::<T>::get(collection, item)?;
+			Collectibles::<T>::get(&collectible_id)?.lessee
+		}
+
+		fn do_extend_rent(collectible: Collectible<T>, blocks: T::BlockNumber) -> DispatchResult {
+			let collectible = Self::at_current_price(collectible);
+			let lessee = collectible.lessee.as_ref().ok_or(Error::<T>::NoLessee)?;
+			ensure!(collectible.price_per_block.is_some(), Error::<T>::RentNotAvailable);
+
+			let lessee_rental = LesseeCollectibles::<T>::get(&lessee, &collectible.collectible_id)
+				.ok_or(Error::<T>::NoCollectible)?;
+			let agreement = RentalAgreements::<T>::get(lessee_rental.agreement_id)
+				.ok_or(Error::<T>::NoCollectible)?;
+			ensure!(lessee_rental.status == RentalStatus::Active, Error::<T>::PaymentOverdue);
+
+			let additional_blocks = convert_to_primitive::<T::BlockNumber, u32>(blocks)
+				.map_err(|_| Error::<T>::Overflow)?;
+			ensure!(additional_blocks > 0, Error::<T>::RentalPeriodTooShort);
+
+			// The current rental period, including the extension, must stay within the maximum
+			if let Some(maximum_rental_period) = collectible.maximum_rental_period {
+				let extended_period = agreement
+					.period_end
+					.checked_sub(&agreement.period_start)
+					.and_then(|period| period.checked_add(&blocks))
+					.ok_or(Error::<T>::Overflow)?;
+
+				ensure!(
+					extended_period <= maximum_rental_period.into(),
+					Error::<T>::RentalPeriodTooLong
+				);
+			}
+
+			let total_rent_price = T::RentalPricing::rent_price(
+				&collectible,
+				lessee,
+				additional_blocks,
+				frame_system::Pallet::<T>::block_number(),
+			)?;
+
+			Self::collect_rent(
+				lessee_rental.agreement_id,
+				agreement.payment_mode,
+				&lessee,
+				&collectible.lessor,
+				collectible.collectible_id,
+				total_rent_price,
+				T::RentalPricing::applied_tier(&collectible, additional_blocks),
+			)?;
+
+			let next_rent_block = lessee_rental.next_rent_block;
+
+			// Remove old rental from pending rentals since we are extending it
+			let mut pending_rental = PendingRentals::<T>::get(&next_rent_block);
+			pending_rental.retain(|(id, _)| *id != collectible.collectible_id);
+			PendingRentals::<T>::insert(&next_rent_block, &pending_rental);
+
+			Self::deposit_event(Event::RentalPeriodRemoved {
+				agreement: lessee_rental.agreement_id,
+				collectible: collectible.collectible_id,
+				at_block: next_rent_block,
+			});
+
+			// Add new rental to pending rentals to extend the rent
+			let next_rent_block = Self::append_pending_rental_to_available_block(
+				Some(next_rent_block),
+				additional_blocks,
+				collectible.collectible_id,
+				&lessee,
+				lessee_rental.agreement_id,
+			)?;
+			ensure!(
+				Self::ends_before_reservations(collectible.collectible_id, next_rent_block),
+				Error::<T>::ReservationConflict
+			);
+
+			// overwrite rental configuration for the collectible
+			let rental_config = RentalPeriodConfig { next_rent_block, ..lessee_rental };
+			LesseeCollectibles::<T>::insert(lessee, &collectible.collectible_id, &rental_config);
+
+			RentalAgreements::<T>::mutate(lessee_rental.agreement_id, |agreement| {
+				if let Some(agreement) = agreement {
+					agreement.period_end = next_rent_block;
+					agreement.total_paid = agreement.total_paid.saturating_add(total_rent_price);
+					agreement.period_rent = agreement.period_rent.saturating_add(total_rent_price);
+
+					if agreement.payment_mode == PaymentMode::Escrow {
+						agreement.escrowed = agreement.escrowed.saturating_add(total_rent_price);
+					}
+				}
+			});
 
 			Self::deposit_event(Event::RentalExtended {
+				agreement: lessee_rental.agreement_id,
 				lessor: collectible.lessor,
 				lessee: lessee.clone(),
 				collectible: collectible.collectible_id,
 				next_rent_block,
 			});
 
-			Self::deposit_event(Event::RentalPeriodAdded {
-				collectible: collectible.collectible_id,
-				next_rent_block,
-			});
+			Self::deposit_event(Event::RentalPeriodAdded {
+				agreement: lessee_rental.agreement_id,
+				collectible: collectible.collectible_id,
+				next_rent_block,
+			});
+
+			T::OnRentalLifecycle::on_rental_renewed(
+				&collectible.collectible_id,
+				lessee,
+				&next_rent_block,
+			);
+
+			// An extension adds to the utilization, but the price is only adjusted on renewals
+			Self::record_rental_period(
+				collectible.collectible_id,
+				lessee_rental.next_rent_block,
+				next_rent_block,
+			);
+
+			Ok(())
+		}
+
+		fn do_pay_overdue(collectible: Collectible<T>, lessee: T::AccountId) -> DispatchResult {
+			let collectible_id = collectible.collectible_id;
+
+			let mut rental_config = LesseeCollectibles::<T>::get(&lessee, &collectible_id)
+				.ok_or(Error::<T>::NoCollectible)?;
+			let due_block = match rental_config.status {
+				RentalStatus::PaymentOverdue { since } => since,
+				RentalStatus::Active => return Err(Error::<T>::NoPaymentOverdue.into()),
+			};
+			let agreement_id = rental_config.agreement_id;
+
+			ensure!(collectible.price_per_block.is_some(), Error::<T>::RentNotAvailable);
+			// The overdue rent is priced like the renewal that was due
+			let escalated_price = Self::escalated_price(agreement_id, &collectible, due_block);
+			let collectible = match escalated_price {
+				Some(price_per_block) =>
+					Collectible { price_per_block: Some(price_per_block), ..collectible },
+				None => Self::at_current_price(collectible),
+			};
+
+			let total_rent_price = T::RentalPricing::rent_price(
+				&collectible,
+				&lessee,
+				rental_config.rental_periodic_interval,
+				frame_system::Pallet::<T>::block_number(),
+			)?;
+			let payment_mode = RentalAgreements::<T>::get(agreement_id)
+				.map_or(PaymentMode::Upfront, |agreement| agreement.payment_mode);
+
+			Self::collect_rent(
+				agreement_id,
+				payment_mode,
+				&lessee,
+				&collectible.lessor,
+				collectible_id,
+				total_rent_price,
+				T::RentalPricing::applied_tier(
+					&collectible,
+					rental_config.rental_periodic_interval,
+				),
+			)?;
+
+			Self::deposit_event(Event::OverdueRentPaid {
+				agreement: agreement_id,
+				lessee: lessee.clone(),
+				collectible: collectible_id,
+				amount: total_rent_price,
+			});
+
+			// The rental is no longer terminated at the end of the grace period
+			let deadline = rental_config.next_rent_block;
+			PendingRentals::<T>::mutate(&deadline, |pending_rentals| {
+				pending_rentals.retain(|(id, account)| *id != collectible_id || *account != lessee)
+			});
+
+			// The paid rental period started when the rent was due, but never ends in the past
+			let period_end = (due_block + rental_config.rental_periodic_interval.into())
+				.max(frame_system::Pallet::<T>::block_number() + One::one());
+			let next_rent_block = Self::append_pending_rental_to_available_block(
+				Some(period_end),
+				0,
+				collectible_id,
+				&lessee,
+				agreement_id,
+			)?;
+			ensure!(
+				Self::ends_before_reservations(collectible_id, next_rent_block),
+				Error::<T>::ReservationConflict
+			);
+
+			rental_config.next_rent_block = next_rent_block;
+			rental_config.status = RentalStatus::Active;
+			LesseeCollectibles::<T>::insert(&lessee, &collectible_id, &rental_config);
+
+			RentalAgreements::<T>::mutate(agreement_id, |agreement| {
+				if let Some(agreement) = agreement {
+					agreement.period_start = due_block;
+					agreement.period_end = next_rent_block;
+					agreement.settled_until = due_block;
+					agreement.total_paid = agreement.total_paid.saturating_add(total_rent_price);
+					agreement.period_rent = total_rent_price;
+					agreement.price_per_block =
+						collectible.price_per_block.unwrap_or(agreement.price_per_block);
+					agreement.renewals = agreement.renewals.saturating_add(1);
+
+					if agreement.payment_mode == PaymentMode::Escrow {
+						agreement.escrowed = agreement.escrowed.saturating_add(total_rent_price);
+					}
+				}
+			});
+
+			if let Some(price_per_block) = escalated_price {
+				Self::deposit_event(Event::PriceEscalated {
+					agreement: agreement_id,
+					collectible: collectible_id,
+					price_per_block,
+				});
+			}
+
+			T::OnRentalLifecycle::on_rental_renewed(&collectible_id, &lessee, &next_rent_block);
+
+			Self::record_rental_period(collectible_id, due_block, next_rent_block);
+			Self::adjust_dynamic_price(collectible_id);
+
+			Ok(())
+		}
+
+		fn do_end_rental(mut collectible: Collectible<T>, lessee: T::AccountId) -> DispatchResult {
+			let collectible_id = collectible.collectible_id;
+
+			let lessee_rental = LesseeCollectibles::<T>::get(&lessee, &collectible_id)
+				.ok_or(Error::<T>::NoCollectible)?;
+			let agreement_id = lessee_rental.agreement_id;
+
+			// The agreement is dropped together with the rental, so the rent is settled first
+			let (payment_mode, refund) = match RentalAgreements::<T>::get(agreement_id) {
+				Some(mut agreement) => {
+					Self::release_elapsed_rent(
+						agreement_id,
+						&mut agreement,
+						frame_system::Pallet::<T>::block_number(),
+					);
+
+					let refund = Self::unused_rent_refund(
+						&agreement,
+						RefundPolicies::<T>::get(&collectible_id),
+					);
+
+					// The lessor keeps the escrowed rent that is not refunded, the refund is
+					// unreserved together with the rental records.
+					if agreement.payment_mode == PaymentMode::Escrow {
+						let kept = agreement.escrowed.saturating_sub(refund);
+						Self::release_escrowed_rent(agreement_id, &mut agreement, kept);
+						RentalAgreements::<T>::insert(agreement_id, &agreement);
+					}
+
+					(agreement.payment_mode, refund)
+				},
+				None => (PaymentMode::Upfront, Zero::zero()),
+			};
+
+			let next_rent_block = lessee_rental.next_rent_block;
+			PendingRentals::<T>::mutate(&next_rent_block, |pending_rentals| {
+				pending_rentals.retain(|(id, account)| *id != collectible_id || *account != lessee)
+			});
+
+			Self::deposit_event(Event::RentalPeriodRemoved {
+				agreement: agreement_id,
+				collectible: collectible_id,
+				at_block: next_rent_block,
+			});
+
+			Self::close_rental(agreement_id, &lessee, &mut collectible);
+
+			let lessor = collectible.lessor;
+			// Upfront rent paid the royalty to the collection creator, so the lessor only refunds
+			// their own share of it.
+			let refund = match payment_mode {
+				PaymentMode::Upfront => refund.saturating_sub(
+					Self::collection_royalty(collectible_id, &lessor, refund)
+						.map_or(Zero::zero(), |(_, _, royalty)| royalty),
+				),
+				PaymentMode::Escrow => refund,
+			};
+
+			if refund.is_zero() {
+				return Ok(())
+			}
+
+			// The rental ends all the same, the lessee claims the refund once the lessor can pay
+			if payment_mode == PaymentMode::Upfront &&
+				Self::transfer_funds(&lessor, &lessee, refund).is_err()
+			{
+				OwedRefunds::<T>::mutate(&lessor, &lessee, |owed| *owed = owed.saturating_add(refund));
+
+				Self::deposit_event(Event::RefundOwed {
+					agreement: agreement_id,
+					lessor,
+					lessee,
+					collectible: collectible_id,
+					amount: refund,
+				});
+
+				return Ok(())
+			}
+
+			Self::deposit_event(Event::RentRefunded {
+				agreement: agreement_id,
+				lessor,
+				lessee,
+				collectible: collectible_id,
+				amount: refund,
+			});
+
+			Ok(())
+		}
+
+		// The rent paid for the blocks of the current rental period that are not used yet, as
+		// far as `policy` allows it to be refunded.
+		fn unused_rent_refund(
+			agreement: &RentalAgreement<T>,
+			policy: RefundPolicy,
+		) -> BalanceOf<T> {
+			let unused_rent = match agreement.payment_mode {
+				PaymentMode::Upfront => {
+					let now = frame_system::Pallet::<T>::block_number();
+					let used_rent = Self::period_rent_until(agreement, now);
+
+					agreement.period_rent.saturating_sub(used_rent)
+				},
+				// The rent of the elapsed blocks was released, the rest is still escrowed
+				PaymentMode::Escrow => agreement.escrowed,
+			};
+
+			match policy {
+				RefundPolicy::NoRefund => Zero::zero(),
+				RefundPolicy::ProRata => unused_rent,
+				RefundPolicy::Partial(share) => share.mul_floor(unused_rent),
+			}
+		}
+
+		// Takes the rent of a rental period from the lessee. Depending on `payment_mode` the rent
+		// is paid to the lessor right away or reserved until the rental period elapses.
+		fn collect_rent(
+			agreement_id: AgreementId,
+			payment_mode: PaymentMode,
+			lessee: &T::AccountId,
+			lessor: &T::AccountId,
+			collectible_id: CollectibleId,
+			amount: BalanceOf<T>,
+			tier: Option<PricingTier>,
+		) -> DispatchResult {
+			match payment_mode {
+				PaymentMode::Upfront => {
+					let royalty = Self::collection_royalty(collectible_id, lessor, amount);
+
+					// Either both the lessor and the creator are paid or neither is
+					with_storage_layer(|| -> DispatchResult {
+						let royalty_amount =
+							royalty.as_ref().map_or(Zero::zero(), |(_, _, royalty)| *royalty);
+						let lessor_amount = amount.saturating_sub(royalty_amount);
+						Self::transfer_funds(lessee, lessor, lessor_amount)?;
+
+						if let Some((collection, creator, royalty_amount)) = royalty {
+							Self::transfer_funds(lessee, &creator, royalty_amount)?;
+
+							Self::deposit_event(Event::RoyaltyPaid {
+								agreement: agreement_id,
+								collection,
+								creator,
+								collectible: collectible_id,
+								amount: royalty_amount,
+							});
+						}
+
+						Ok(())
+					})?;
+				},
+				PaymentMode::Escrow => {
+					T::Currency::reserve(lessee, amount)
+						.map_err(|_| Error::<T>::NotEnoughBalance)?;
+
+					Self::deposit_event(Event::RentEscrowed {
+						agreement: agreement_id,
+						lessee: lessee.clone(),
+						collectible: collectible_id,
+						amount,
+					});
+				},
+			}
+
+			Self::deposit_event(Event::RentPayed {
+				agreement: agreement_id,
+				lessee: lessee.clone(),
+				lessor: lessor.clone(),
+				collectible: collectible_id,
+				total_rent_price: amount,
+				tier,
+			});
+
+			Ok(())
+		}
+
+		// Gives the lessee `PaymentGracePeriod` blocks to pay the rent of the rental period that
+		// starts in `block_number`, or terminates the rental right away if that is not possible.
+		fn start_grace_period(
+			block_number: T::BlockNumber,
+			rental_config: RentalPeriodConfig<T>,
+			lessee: &T::AccountId,
+			collectible: &mut Collectible<T>,
+			amount: BalanceOf<T>,
+		) {
+			let agreement_id = rental_config.agreement_id;
+			let collectible_id = collectible.collectible_id;
+			let grace_period = T::PaymentGracePeriod::get();
+
+			let deadline = match Self::find_available_block(block_number + grace_period) {
+				Ok(deadline) if !grace_period.is_zero() =>
+					PendingRentals::<T>::try_append(deadline, (collectible_id, lessee.clone()))
+						.ok()
+						.map(|_| deadline),
+				_ => None,
+			};
+
+			match deadline {
+				Some(deadline) => {
+					LesseeCollectibles::<T>::insert(
+						lessee,
+						&collectible_id,
+						RentalPeriodConfig {
+							next_rent_block: deadline,
+							status: RentalStatus::PaymentOverdue { since: block_number },
+							..rental_config
+						},
+					);
+
+					Self::deposit_event(Event::RentPaymentOverdue {
+						agreement: agreement_id,
+						lessee: lessee.clone(),
+						collectible: collectible_id,
+						amount,
+						deadline,
+					});
+				},
+				None => {
+					Self::remove_lessee_from_collectible(lessee, collectible);
+
+					Self::deposit_event(Event::ErrorTransferingRent {
+						agreement: agreement_id,
+						lessee: lessee.clone(),
+						lessor: collectible.lessor.clone(),
+						collectible: collectible_id,
+					});
+				},
+			}
+		}
+
+		// Releases the escrowed rent of the blocks of the current rental period that elapsed up
+		// to `until` to the lessor.
+		fn release_elapsed_rent(
+			agreement_id: AgreementId,
+			agreement: &mut RentalAgreement<T>,
+			until: T::BlockNumber,
+		) {
+			let until = until.min(agreement.period_end);
+			if agreement.payment_mode != PaymentMode::Escrow || until <= agreement.settled_until {
+				return
+			}
+
+			// Whatever of the rent of the current rental period is no longer escrowed was released
+			let released_rent = agreement.period_rent.saturating_sub(agreement.escrowed);
+			let elapsed_rent =
+				Self::period_rent_until(agreement, until).saturating_sub(released_rent);
+
+			Self::release_escrowed_rent(agreement_id, agreement, elapsed_rent);
+			agreement.settled_until = until;
+		}
+
+		// The share of the rent paid for the current rental period that falls on its blocks
+		// before `until`.
+		fn period_rent_until(
+			agreement: &RentalAgreement<T>,
+			until: T::BlockNumber,
+		) -> BalanceOf<T> {
+			if until >= agreement.period_end || agreement.period_end <= agreement.period_start {
+				return agreement.period_rent
+			}
+
+			let to_primitive = |blocks: T::BlockNumber| {
+				convert_to_primitive::<T::BlockNumber, u32>(blocks).unwrap_or(u32::MAX)
+			};
+			let period_blocks = to_primitive(agreement.period_end - agreement.period_start);
+			let elapsed_blocks = to_primitive(until.saturating_sub(agreement.period_start));
+
+			agreement.period_rent.saturating_mul(elapsed_blocks.into()) / period_blocks.into()
+		}
+
+		// Pays up to `amount` of the escrowed rent to the lessor, minus the royalty of the
+		// collection creator.
+		fn release_escrowed_rent(
+			agreement_id: AgreementId,
+			agreement: &mut RentalAgreement<T>,
+			amount: BalanceOf<T>,
+		) {
+			let amount = amount.min(agreement.escrowed);
+			if amount.is_zero() {
+				return
+			}
+
+			let mut royalty_paid: BalanceOf<T> = Zero::zero();
+			if let Some((collection, creator, royalty)) =
+				Self::collection_royalty(agreement.collectible_id, &agreement.lessor, amount)
+			{
+				if let Ok(not_paid) = T::Currency::repatriate_reserved(
+					&agreement.lessee,
+					&creator,
+					royalty,
+					BalanceStatus::Free,
+				) {
+					royalty_paid = royalty.saturating_sub(not_paid);
+
+					Self::deposit_event(Event::RoyaltyPaid {
+						agreement: agreement_id,
+						collection,
+						creator,
+						collectible: agreement.collectible_id,
+						amount: royalty_paid,
+					});
+				}
+			}
+
+			let to_lessor = amount.saturating_sub(royalty_paid);
+			let released = match T::Currency::repatriate_reserved(
+				&agreement.lessee,
+				&agreement.lessor,
+				to_lessor,
+				BalanceStatus::Free,
+			) {
+				// Only the part that could not be moved is returned
+				Ok(not_released) => to_lessor.saturating_sub(not_released),
+				Err(_) => Zero::zero(),
+			};
+
+			agreement.escrowed =
+				agreement.escrowed.saturating_sub(released.saturating_add(royalty_paid));
+
+			if released.is_zero() {
+				return
+			}
+
+			Self::deposit_event(Event::RentReleased {
+				agreement: agreement_id,
+				lessor: agreement.lessor.clone(),
+				lessee: agreement.lessee.clone(),
+				collectible: agreement.collectible_id,
+				amount: released,
+			});
+		}
+
+		/// Processes the rentals scheduled up to block `n`, oldest first, without spending more
+		/// than `max_weight`. Rentals that do not fit are left in `PendingRentals` and picked up
+		/// by the next call. Returns the weight used.
+		fn do_process_rental_periods(n: T::BlockNumber, max_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// A pending rental either renews or ends a rental, or starts a reservation
+			let rental_weight =
+				T::WeightInfo::process_rental().max(T::WeightInfo::activate_reservation());
+
+			// Reading and updating the processing cursor.
+			let mut used_weight = db_weight.reads_writes(1, 1);
+			if used_weight.any_gt(max_weight) {
+				return Weight::zero()
+			}
+
+			let mut block_number = NextPendingRentalsBlock::<T>::get().unwrap_or(n);
+
+			while block_number <= n {
+				// Reading and writing back the rentals of the block.
+				let block_weight = db_weight.reads_writes(1, 1);
+				if used_weight.saturating_add(block_weight).any_gt(max_weight) {
+					break
+				}
+				used_weight = used_weight.saturating_add(block_weight);
+
+				let rentals = PendingRentals::<T>::get(block_number);
+
+				let mut processed = 0;
+				for (collectible_id, lessee) in rentals.iter() {
+					if used_weight.saturating_add(rental_weight).any_gt(max_weight) {
+						break
+					}
+					used_weight = used_weight.saturating_add(rental_weight);
+
+					Self::process_rental(block_number, *collectible_id, lessee.clone());
+					processed += 1;
+				}
+
+				if processed < rentals.len() {
+					// Keep the rentals that did not fit for the next call.
+					let remaining = BoundedVec::truncate_from(rentals[processed..].to_vec());
+					PendingRentals::<T>::insert(block_number, remaining);
+					break
+				}
+
+				PendingRentals::<T>::remove(block_number);
+				block_number += One::one();
+			}
+
+			NextPendingRentalsBlock::<T>::put(block_number);
+
+			used_weight
+		}
+
+		fn process_rental(
+			block_number: T::BlockNumber,
+			collectible_id: CollectibleId,
+			lessee: T::AccountId,
+		) {
+			let rental_config = LesseeCollectibles::<T>::get(&lessee, &collectible_id);
+
+			// Unless a rental of the lessee ends in this block, the entry may start a reservation
+			let ends_now = rental_config
+				.as_ref()
+				.map_or(false, |rental_config| rental_config.next_rent_block == block_number);
+			if !ends_now && Self::activate_reservation(block_number, collectible_id, &lessee) {
+				return
+			}
+
+			let rental_config = match rental_config {
+				Some(rental_config) => rental_config,
+				None => {
+					// Make sure the collectible does not outlive the rental configuration.
+					if let Some(mut collectible) = Collectibles::<T>::get(&collectible_id) {
+						if collectible.lessee.as_ref() == Some(&lessee) {
+							Self::remove_lessee_from_collectible(&lessee, &mut collectible);
+						}
+					}
+
+					Self::deposit_event(Event::RentalProcessingFailed {
+						agreement: None,
+						collectible: collectible_id,
+						reason: RentalProcessingError::MissingRentalConfig,
+					});
+
+					return
+				},
+			};
+
+			let agreement_id = rental_config.agreement_id;
+
+			// The rental was rescheduled since, this entry is a leftover.
+			if rental_config.next_rent_block != block_number {
+				Self::deposit_event(Event::RentalProcessingFailed {
+					agreement: Some(agreement_id),
+					collectible: collectible_id,
+					reason: RentalProcessingError::StaleSchedule,
+				});
+
+				return
+			}
+
+			let mut collectible = match Collectibles::<T>::get(&collectible_id) {
+				Some(collectible) => collectible,
+				None => {
+					Self::remove_rental_records(&lessee, collectible_id);
+					T::OnRentalLifecycle::on_rental_ended(&collectible_id, &lessee);
+
+					Self::deposit_event(Event::RentalProcessingFailed {
+						agreement: Some(agreement_id),
+						collectible: collectible_id,
+						reason: RentalProcessingError::MissingCollectible,
+					});
+
+					return
+				},
+			};
+
+			if collectible.lessee.is_some() && collectible.lessee.as_ref() != Some(&lessee) {
+				// Leave the current lessee alone and only drop what belongs to this rental.
+				Self::remove_rental_records(&lessee, collectible_id);
+				T::OnRentalLifecycle::on_rental_ended(&collectible_id, &lessee);
+
+				Self::deposit_event(Event::RentalProcessingFailed {
+					agreement: Some(agreement_id),
+					collectible: collectible_id,
+					reason: RentalProcessingError::LesseeMismatch,
+				});
+
+				return
+			}
+
+			// Everything escrowed for the rental period that just ended is due to the lessor
+			RentalAgreements::<T>::mutate(agreement_id, |agreement| {
+				if let Some(agreement) = agreement {
+					Self::release_elapsed_rent(agreement_id, agreement, block_number);
+				}
+			});
+
+			// The grace period is over and the rent was not paid
+			if let RentalStatus::PaymentOverdue { .. } = rental_config.status {
+				Self::remove_lessee_from_collectible(&lessee, &mut collectible);
+
+				Self::deposit_event(Event::ErrorTransferingRent {
+					agreement: agreement_id,
+					lessee: lessee.clone(),
+					lessor: collectible.lessor.clone(),
+					collectible: collectible_id,
+				});
+
+				return
+			}
+
+			if !collectible.rentable || None == collectible.lessee || !rental_config.recurring {
+				Self::close_rental(agreement_id, &lessee, &mut collectible);
+				return
+			}
+
+			if collectible.price_per_block.is_none() {
+				Self::close_rental(agreement_id, &lessee, &mut collectible);
+
+				Self::deposit_event(Event::RentalProcessingFailed {
+					agreement: Some(agreement_id),
+					collectible: collectible_id,
+					reason: RentalProcessingError::MissingPrice,
+				});
+
+				return
+			}
+
+			// The next period starts where this one was scheduled to end, even if processing it
+			// was carried over to a later block.
+			let next_rent_block = match Self::find_available_block(
+				block_number + rental_config.rental_periodic_interval.into(),
+			) {
+				Ok(next_rent_block) => next_rent_block,
+				Err(_) => {
+					Self::close_rental(agreement_id, &lessee, &mut collectible);
+
+					Self::deposit_event(Event::RentalProcessingFailed {
+						agreement: Some(agreement_id),
+						collectible: collectible_id,
+						reason: RentalProcessingError::NoAvailableRentalSlot,
+					});
 
-			Ok(())
-		}
+					return
+				},
+			};
 
-		fn do_process_rental_periods(n: T::BlockNumber) {
-			let rentals = PendingRentals::<T>::get(n);
+			if !Self::ends_before_reservations(collectible_id, next_rent_block) {
+				Self::close_rental(agreement_id, &lessee, &mut collectible);
 
-			for rental in rentals {
-				let collectible_id = rental.0.clone();
-				let lessee = rental.1.clone();
+				Self::deposit_event(Event::RentalProcessingFailed {
+					agreement: Some(agreement_id),
+					collectible: collectible_id,
+					reason: RentalProcessingError::ReservationConflict,
+				});
 
-				let rental_config = LesseeCollectibles::<T>::get(&lessee, &collectible_id).unwrap();
+				return
+			}
 
-				let mut collectible = Collectibles::<T>::get(&collectible_id)
-					.ok_or(Error::<T>::NoCollectible)
-					.unwrap();
+			// The price of the collectible stays as is, an escalation only applies to the rental
+			let escalated_price = Self::escalated_price(agreement_id, &collectible, block_number);
+			let priced = match escalated_price {
+				Some(price_per_block) =>
+					Collectible { price_per_block: Some(price_per_block), ..collectible.clone() },
+				None => Self::at_current_price(collectible.clone()),
+			};
 
-				if !collectible.rentable || None == collectible.lessee || !rental_config.recurring {
-					Self::remove_lessee_from_collectible(&lessee, &mut collectible).unwrap();
+			let total_rent_price = match T::RentalPricing::rent_price(
+				&priced,
+				&lessee,
+				rental_config.rental_periodic_interval,
+				frame_system::Pallet::<T>::block_number(),
+			) {
+				Ok(total_rent_price) => total_rent_price,
+				Err(_) => {
+					Self::close_rental(agreement_id, &lessee, &mut collectible);
 
-					Self::deposit_event(Event::RentalEnded {
-						lessee: lessee.clone(),
-						lessor: collectible.lessor.clone(),
+					Self::deposit_event(Event::RentalProcessingFailed {
+						agreement: Some(agreement_id),
 						collectible: collectible_id,
+						reason: RentalProcessingError::PricingFailed,
 					});
 
-					continue
-				}
+					return
+				},
+			};
 
-				let total_rent_price = collectible.price_per_block.unwrap() *
-					rental_config.rental_periodic_interval.into();
+			let payment_mode = RentalAgreements::<T>::get(agreement_id)
+				.map_or(PaymentMode::Upfront, |agreement| agreement.payment_mode);
 
-				// Mutating state with a balance transfer, so nothing is allowed to fail after
-				// this.
-				if let Err(_) = Self::transfer_funds(&lessee, &collectible.lessor, total_rent_price)
-				{
-					Self::remove_lessee_from_collectible(&lessee, &mut collectible).unwrap();
+			if let Err(_) = Self::collect_rent(
+				agreement_id,
+				payment_mode,
+				&lessee,
+				&collectible.lessor,
+				collectible_id,
+				total_rent_price,
+				T::RentalPricing::applied_tier(&priced, rental_config.rental_periodic_interval),
+			) {
+				Self::start_grace_period(
+					block_number,
+					rental_config,
+					&lessee,
+					&mut collectible,
+					total_rent_price,
+				);
 
-					Self::deposit_event(Event::ErrorTransferingRent {
-						lessee: lessee.clone(),
-						lessor: collectible.lessor.clone(),
-						collectible: collectible_id,
-					});
+				return
+			}
 
-					continue
+			// Recorded right away so that the rent is returned if the rental is closed below
+			RentalAgreements::<T>::mutate(agreement_id, |agreement| {
+				if let Some(agreement) = agreement {
+					agreement.period_start = block_number;
+					agreement.period_end = next_rent_block;
+					agreement.settled_until = block_number;
+					agreement.total_paid = agreement.total_paid.saturating_add(total_rent_price);
+					agreement.period_rent = total_rent_price;
+					agreement.price_per_block =
+						priced.price_per_block.unwrap_or(agreement.price_per_block);
+					agreement.renewals = agreement.renewals.saturating_add(1);
+
+					if agreement.payment_mode == PaymentMode::Escrow {
+						agreement.escrowed = agreement.escrowed.saturating_add(total_rent_price);
+					}
 				}
+			});
 
-				Self::deposit_event(Event::RentPayed {
-					lessee: lessee.clone(),
-					lessor: collectible.lessor.clone(),
+			if let Some(price_per_block) = escalated_price {
+				Self::deposit_event(Event::PriceEscalated {
+					agreement: agreement_id,
 					collectible: collectible_id,
-					total_rent_price,
+					price_per_block,
 				});
+			}
 
-				// Add the rental period again if recurring
-				if rental_config.recurring {
-					let next_rent_block = Self::append_pending_rental_to_available_block(
-						None,
-						rental_config.rental_periodic_interval,
-						collectible_id,
-						&lessee,
-					)
-					.unwrap();
-
+			// The block was checked for room above, so this only fails on a storage inconsistency.
+			match Self::append_pending_rental_to_available_block(
+				Some(next_rent_block),
+				0,
+				collectible_id,
+				&lessee,
+				agreement_id,
+			) {
+				Ok(next_rent_block) => {
 					LesseeCollectibles::<T>::insert(
 						&lessee,
 						&collectible_id,
 						RentalPeriodConfig { next_rent_block, ..rental_config },
 					);
-				}
-			}
 
-			PendingRentals::<T>::remove(n);
+					RentalAgreements::<T>::mutate(agreement_id, |agreement| {
+						if let Some(agreement) = agreement {
+							agreement.period_end = next_rent_block;
+						}
+					});
+
+					T::OnRentalLifecycle::on_rental_renewed(
+						&collectible_id,
+						&lessee,
+						&next_rent_block,
+					);
+
+					Self::record_rental_period(collectible_id, block_number, next_rent_block);
+					Self::adjust_dynamic_price(collectible_id);
+				},
+				Err(_) => {
+					Self::close_rental(agreement_id, &lessee, &mut collectible);
+
+					Self::deposit_event(Event::RentalProcessingFailed {
+						agreement: Some(agreement_id),
+						collectible: collectible_id,
+						reason: RentalProcessingError::NoAvailableRentalSlot,
+					});
+				},
+			}
 		}
 	}
 
@@ -681,35 +3776,47 @@ pub mod pallet {
 			frame_support::Hashable::blake2_128(&encoded_payload)
 		}
 
+		/// Returns the first block from `block_number` onwards that has room in
+		/// `PendingRentals`, looking at no more than `MaximumSchedulingProbes` blocks.
+		fn find_available_block(
+			block_number: T::BlockNumber,
+		) -> Result<T::BlockNumber, DispatchError> {
+			let mut block_number = block_number;
+
+			for _ in 0..T::MaximumSchedulingProbes::get() {
+				let scheduled = PendingRentals::<T>::decode_len(block_number).unwrap_or_default();
+
+				if (scheduled as u32) < T::MaximumRentablesPerBlock::get() {
+					return Ok(block_number)
+				}
+
+				block_number += One::one();
+			}
+
+			Err(Error::<T>::NoAvailableRentalSlot.into())
+		}
+
 		fn append_pending_rental_to_available_block(
 			starting_block_number: Option<T::BlockNumber>,
 			additional_rental_blocks: u32,
 			collectible_id: CollectibleId,
 			lessee: &T::AccountId,
+			agreement_id: AgreementId,
 		) -> Result<T::BlockNumber, DispatchError> {
 			let starting_block_number = match starting_block_number {
 				Some(block_number) => block_number,
 				None => frame_system::Pallet::<T>::block_number(),
 			};
 
-			let mut block_number = starting_block_number + additional_rental_blocks.into();
-
-			let mut rental_periods = PendingRentals::<T>::get(block_number);
-
-			// try to append the collectible to the rental period
-			// if it fails (because the rental period is already full), increment the rental period
-			// and try again
-			while let Err(_) =
-				rental_periods.try_append(&mut vec![(collectible_id, lessee.clone())])
-			{
-				let next_block_number: T::BlockNumber = (1 as u32).into();
-				block_number = frame_system::Pallet::<T>::block_number() + next_block_number;
-				rental_periods = PendingRentals::<T>::get(block_number);
-			}
+			let block_number = Self::find_available_block(
+				starting_block_number + additional_rental_blocks.into(),
+			)?;
 
-			PendingRentals::<T>::insert(block_number, rental_periods);
+			PendingRentals::<T>::try_append(block_number, (collectible_id, lessee.clone()))
+				.map_err(|_| Error::<T>::NoAvailableRentalSlot)?;
 
 			Self::deposit_event(Event::RentalPeriodAdded {
+				agreement: agreement_id,
 				collectible: collectible_id,
 				next_rent_block: block_number,
 			});
@@ -717,21 +3824,514 @@ pub mod pallet {
 			Ok(block_number)
 		}
 
-		fn remove_lessee_from_collectible(
+		// Ends the rental of `lessee` and notifies about it.
+		fn close_rental(
+			agreement_id: AgreementId,
 			lessee: &T::AccountId,
 			collectible: &mut Collectible<T>,
-		) -> DispatchResult {
+		) {
+			Self::remove_lessee_from_collectible(lessee, collectible);
+
+			Self::deposit_event(Event::RentalEnded {
+				agreement: agreement_id,
+				lessee: lessee.clone(),
+				lessor: collectible.lessor.clone(),
+				collectible: collectible.collectible_id,
+			});
+		}
+
+		fn remove_lessee_from_collectible(lessee: &T::AccountId, collectible: &mut Collectible<T>) {
 			let collectible_id = collectible.collectible_id;
-			LesseeCollectibles::<T>::remove(&lessee, &collectible_id);
 
 			collectible.lessee = None;
 			Collectibles::<T>::insert(&collectible_id, collectible);
 
+			Self::remove_rental_records(lessee, collectible_id);
+			Self::truncate_rental_period(collectible_id);
+
+			T::OnRentalLifecycle::on_rental_ended(&collectible_id, lessee);
+		}
+
+		// Drops the rental configuration and agreement of `lessee` without touching the
+		// collectible itself.
+		fn remove_rental_records(lessee: &T::AccountId, collectible_id: CollectibleId) {
+			if let Some(rental_config) = LesseeCollectibles::<T>::take(&lessee, &collectible_id) {
+				// Rent that is still escrowed at this point was not earned by the lessor
+				if let Some(agreement) = RentalAgreements::<T>::take(rental_config.agreement_id) {
+					T::Currency::unreserve(lessee, agreement.escrowed);
+				}
+
+				Escalations::<T>::remove(rental_config.agreement_id);
+
+				Self::release_deposit(rental_config.agreement_id);
+			}
+
 			Self::unequip_collectible_from_account(lessee.clone(), collectible_id);
+		}
+
+		// Returns the security deposit of a rental that ended to the lessee, unless the lessor
+		// has an open claim on it.
+		fn release_deposit(agreement_id: AgreementId) {
+			let mut deposit = match Deposits::<T>::get(agreement_id) {
+				Some(deposit) => deposit,
+				None => return,
+			};
+
+			if deposit.claim.is_some() {
+				deposit.rental_ended = true;
+				Deposits::<T>::insert(agreement_id, deposit);
+				return
+			}
+
+			Deposits::<T>::remove(agreement_id);
+			T::Currency::unreserve(&deposit.lessee, deposit.amount);
+
+			Self::deposit_event(Event::DepositReleased {
+				agreement: agreement_id,
+				lessee: deposit.lessee,
+				amount: deposit.amount,
+			});
+		}
+
+		// Pays up to `amount` of the security deposit to the lessor.
+		fn slash_deposit(
+			agreement_id: AgreementId,
+			deposit: &mut SecurityDeposit<T>,
+			amount: BalanceOf<T>,
+		) {
+			let amount = amount.min(deposit.amount);
+			if amount.is_zero() {
+				return
+			}
+
+			let slashed = match T::Currency::repatriate_reserved(
+				&deposit.lessee,
+				&deposit.lessor,
+				amount,
+				BalanceStatus::Free,
+			) {
+				// Only the part that could not be moved is returned
+				Ok(not_slashed) => amount.saturating_sub(not_slashed),
+				Err(_) => return,
+			};
+
+			deposit.amount = deposit.amount.saturating_sub(slashed);
+
+			Self::deposit_event(Event::DepositSlashed {
+				agreement: agreement_id,
+				lessor: deposit.lessor.clone(),
+				lessee: deposit.lessee.clone(),
+				amount: slashed,
+			});
+		}
+
+		// Drops the claim on a security deposit and returns the deposit if the rental is over.
+		fn close_deposit_claim(agreement_id: AgreementId, mut deposit: SecurityDeposit<T>) {
+			deposit.claim = None;
+			Deposits::<T>::insert(agreement_id, &deposit);
+
+			if deposit.rental_ended {
+				Self::release_deposit(agreement_id);
+			}
+		}
+
+		pub(crate) fn metadata_or_default(
+			collectible_id: CollectibleId,
+			lessor: &T::AccountId,
+		) -> CollectibleMetadata<T> {
+			MetadataOf::<T>::get(&collectible_id).unwrap_or_else(|| CollectibleMetadata {
+				data: Default::default(),
+				attributes: Default::default(),
+				depositor: lessor.clone(),
+				deposit: Zero::zero(),
+			})
+		}
+
+		// Stores the metadata of a collectible and adjusts the deposit reserved from the lessor
+		// to its size. A deposit made by a previous lessor is returned to them.
+		pub(crate) fn store_metadata(
+			lessor: &T::AccountId,
+			collectible_id: CollectibleId,
+			mut metadata: CollectibleMetadata<T>,
+		) -> DispatchResult {
+			let bytes = metadata
+				.attributes
+				.iter()
+				.fold(metadata.data.len(), |bytes, (key, value)| bytes + key.len() + value.len());
+			let deposit = T::DepositPerByte::get()
+				.saturating_mul((bytes as u32).into())
+				.saturating_add(T::MetadataDepositBase::get());
+
+			if metadata.depositor != *lessor {
+				T::Currency::unreserve(&metadata.depositor, metadata.deposit);
+				metadata.depositor = lessor.clone();
+				metadata.deposit = Zero::zero();
+			}
+
+			if deposit > metadata.deposit {
+				T::Currency::reserve(lessor, deposit - metadata.deposit)
+					.map_err(|_| Error::<T>::NotEnoughBalance)?;
+			} else {
+				T::Currency::unreserve(lessor, metadata.deposit - deposit);
+			}
+
+			metadata.deposit = deposit;
+			MetadataOf::<T>::insert(&collectible_id, metadata);
 
 			Ok(())
 		}
 
+		/// The pricing tier of a collectible that applies to a rental period of `blocks` blocks.
+		pub fn pricing_tier(collectible_id: CollectibleId, blocks: u32) -> Option<PricingTier> {
+			PricingTiers::<T>::get(&collectible_id)
+				.into_iter()
+				.rev()
+				.find(|tier| blocks >= tier.minimum_blocks)
+		}
+
+		/// The rent a lessee pays for renting a collectible for `blocks` blocks in the current
+		/// block. The price of a collectible with dynamic pricing follows its utilization, so the
+		/// quote holds within the block and falls while the collectible is idle.
+		pub fn quote_rent(
+			collectible_id: CollectibleId,
+			lessee: &T::AccountId,
+			blocks: u32,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let collectible = Self::at_current_price(Self::fetch_collectible(collectible_id)?);
+
+			T::RentalPricing::rent_price(
+				&collectible,
+				lessee,
+				blocks,
+				frame_system::Pallet::<T>::block_number(),
+			)
+		}
+
+		/// The share of the last `UtilizationWindow` blocks a collectible with dynamic pricing
+		/// was rented.
+		pub fn utilization(collectible_id: CollectibleId) -> Permill {
+			let now = frame_system::Pallet::<T>::block_number();
+			let window = T::UtilizationWindow::get();
+			let window_start = now.saturating_sub(window.into());
+
+			let rented = RentalHistory::<T>::get(&collectible_id)
+				.unwrap_or_default()
+				.into_iter()
+				.fold(T::BlockNumber::zero(), |rented, (start, end)| {
+					rented.saturating_add(end.min(now).saturating_sub(start.max(window_start)))
+				});
+			let rented = convert_to_primitive::<T::BlockNumber, u32>(rented).unwrap_or(window);
+
+			Permill::from_rational(rented.min(window), window)
+		}
+
+		// Records that a collectible with dynamic pricing is rented from `start` until `end`. A
+		// period continuing the last recorded one is merged into it.
+		fn record_rental_period(
+			collectible_id: CollectibleId,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		) {
+			if !DynamicPrices::<T>::contains_key(&collectible_id) {
+				return
+			}
+
+			let window_start = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(T::UtilizationWindow::get().into());
+
+			let mut history = RentalHistory::<T>::get(&collectible_id).unwrap_or_default();
+			history.retain(|(_, period_end)| *period_end > window_start);
+
+			match history.last_mut() {
+				Some(last) if last.1 >= start => last.1 = last.1.max(end),
+				_ => {
+					if let Err(period) = history.try_push((start, end)) {
+						// Forget the oldest period to make room for the new one
+						if !history.is_empty() {
+							history.remove(0);
+							let _ = history.try_push(period);
+						}
+					}
+				},
+			}
+
+			RentalHistory::<T>::insert(&collectible_id, history);
+		}
+
+		// Shortens the last recorded rental period of a collectible with dynamic pricing to end
+		// in the current block.
+		fn truncate_rental_period(collectible_id: CollectibleId) {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			RentalHistory::<T>::mutate_exists(&collectible_id, |history| {
+				if let Some((_, end)) = history.as_mut().and_then(|history| history.last_mut()) {
+					*end = (*end).min(now);
+				}
+			});
+		}
+
+		// Prices a collectible with dynamic pricing at its utilization in the current block. A
+		// collectible that was never made rentable stays without a price.
+		fn at_current_price(mut collectible: Collectible<T>) -> Collectible<T> {
+			if let (Some(pricing), Some(_)) =
+				(DynamicPrices::<T>::get(&collectible.collectible_id), collectible.price_per_block)
+			{
+				let utilization = Self::utilization(collectible.collectible_id);
+				collectible.price_per_block = Some(pricing.price_at(utilization));
+			}
+
+			collectible
+		}
+
+		// Sets the price per block of a collectible with dynamic pricing to its current
+		// utilization.
+		fn adjust_dynamic_price(collectible_id: CollectibleId) {
+			let pricing = match DynamicPrices::<T>::get(&collectible_id) {
+				Some(pricing) => pricing,
+				None => return,
+			};
+
+			let utilization = Self::utilization(collectible_id);
+			let price_per_block = pricing.price_at(utilization);
+
+			// A collectible that was never made rentable stays without a price
+			let adjusted = Collectibles::<T>::mutate(&collectible_id, |collectible| match collectible {
+				Some(collectible) if collectible.price_per_block.is_some() => {
+					collectible.price_per_block = Some(price_per_block);
+					true
+				},
+				_ => false,
+			});
+
+			if adjusted {
+				Self::deposit_event(Event::PriceAdjusted {
+					collectible: collectible_id,
+					utilization,
+					price_per_block,
+				});
+			}
+		}
+
+		// Removes a rental offer and returns the rent reserved for it.
+		fn remove_offer(
+			collectible_id: CollectibleId,
+			lessee: &T::AccountId,
+		) -> Result<RentalOffer<T>, DispatchError> {
+			let offer =
+				RentalOffers::<T>::take(&collectible_id, lessee).ok_or(Error::<T>::NoOffer)?;
+
+			OfferExpiries::<T>::mutate(offer.expiry, |offers| {
+				offers.retain(|(id, account)| *id != collectible_id || account != lessee)
+			});
+			T::Currency::unreserve(lessee, offer.reserved);
+
+			Ok(offer)
+		}
+
+		// Removes the rental offers expiring in block `n` and returns the rent reserved for them.
+		pub(crate) fn expire_offers(n: T::BlockNumber) -> Weight {
+			let offers = OfferExpiries::<T>::take(n);
+
+			for (collectible_id, lessee) in offers.iter() {
+				let offer = RentalOffers::<T>::get(collectible_id, lessee)
+					.filter(|offer| offer.expiry == n);
+
+				if let Some(offer) = offer {
+					RentalOffers::<T>::remove(collectible_id, lessee);
+					T::Currency::unreserve(lessee, offer.reserved);
+
+					Self::deposit_event(Event::RentalOfferExpired {
+						collectible: *collectible_id,
+						lessee: lessee.clone(),
+					});
+				}
+			}
+
+			T::WeightInfo::expire_offers(offers.len() as u32)
+		}
+
+		// Settles the rental auctions ending in block `n`.
+		pub(crate) fn settle_auctions(n: T::BlockNumber) -> Weight {
+			let collectibles = AuctionEnds::<T>::take(n);
+
+			for collectible_id in collectibles.iter() {
+				let auction =
+					RentalAuctions::<T>::get(collectible_id).filter(|auction| auction.end == n);
+
+				if let Some(auction) = auction {
+					RentalAuctions::<T>::remove(collectible_id);
+					Self::settle_auction(*collectible_id, auction);
+				}
+			}
+
+			T::WeightInfo::settle_auctions(collectibles.len() as u32)
+		}
+
+		// Rents out the collectible of an auction that ended to the highest bidder at their bid.
+		fn settle_auction(collectible_id: CollectibleId, auction: RentalAuction<T>) {
+			let (bidder, price_per_block) = match auction.highest_bid {
+				Some(highest_bid) => highest_bid,
+				None => {
+					Self::deposit_event(Event::AuctionEndedWithoutBids {
+						collectible: collectible_id,
+					});
+					return
+				},
+			};
+
+			T::Currency::unreserve(&bidder, price_per_block.saturating_mul(auction.blocks.into()));
+
+			let rented = with_storage_layer(|| {
+				Self::do_rent_collectible(
+					collectible_id,
+					bidder.clone(),
+					auction.blocks,
+					false,
+					Some(price_per_block),
+					None,
+				)
+			});
+
+			match rented {
+				Ok(agreement_id) => Self::deposit_event(Event::AuctionSettled {
+					agreement: agreement_id,
+					collectible: collectible_id,
+					lessee: bidder,
+					price_per_block,
+				}),
+				Err(_) => Self::deposit_event(Event::AuctionSettlementFailed {
+					collectible: collectible_id,
+					bidder,
+				}),
+			}
+		}
+
+		// Rents out a collectible to `lessee` if they reserved it from block `block_number` on.
+		// Returns whether they had such a reservation.
+		fn activate_reservation(
+			block_number: T::BlockNumber,
+			collectible_id: CollectibleId,
+			lessee: &T::AccountId,
+		) -> bool {
+			let mut reservations = Reservations::<T>::get(&collectible_id);
+			let index = match reservations.iter().position(|reservation| {
+				reservation.start == block_number && reservation.lessee == *lessee
+			}) {
+				Some(index) => index,
+				None => return false,
+			};
+			let reservation = reservations.remove(index);
+			Reservations::<T>::insert(&collectible_id, reservations);
+
+			let rented = with_storage_layer(|| {
+				let collectible = Self::fetch_collectible(collectible_id)?;
+				ensure!(collectible.rentable, Error::<T>::RentNotAvailable);
+				ensure!(collectible.lessee.is_none(), Error::<T>::RentNotAvailable);
+
+				// A reservation processed late still ends in its end block
+				let now = frame_system::Pallet::<T>::block_number();
+				let blocks = reservation.end.saturating_sub(now);
+				let blocks = convert_to_primitive::<T::BlockNumber, u32>(blocks)
+					.map_err(|_| Error::<T>::Overflow)?;
+				ensure!(blocks > 0, Error::<T>::RentalPeriodTooShort);
+
+				Self::do_rent_collectible(
+					collectible_id,
+					lessee.clone(),
+					blocks,
+					false,
+					Some(reservation.price_per_block),
+					Some((reservation.prepaid, reservation.tier)),
+				)
+			});
+
+			match rented {
+				Ok(agreement_id) => Self::deposit_event(Event::ReservationActivated {
+					agreement: agreement_id,
+					collectible: collectible_id,
+					lessee: lessee.clone(),
+				}),
+				Err(_) => {
+					T::Currency::unreserve(lessee, reservation.prepaid);
+
+					Self::deposit_event(Event::ReservationActivationFailed {
+						collectible: collectible_id,
+						lessee: lessee.clone(),
+					});
+				},
+			}
+
+			true
+		}
+
+		// Whether a rental of a collectible ending in block `end` ends before the next reservation
+		// of the collectible starts, so that the rental is processed first.
+		fn ends_before_reservations(collectible_id: CollectibleId, end: T::BlockNumber) -> bool {
+			Reservations::<T>::get(&collectible_id)
+				.first()
+				.map_or(true, |next| end < next.start)
+		}
+
+		// The price per block a rental of a collectible is renewed at in `block_number` if an
+		// escalation scheduled for it is in effect. The escalation raises the price the current
+		// rental period was priced at, never above the maximum of the collection policy.
+		fn escalated_price(
+			agreement_id: AgreementId,
+			collectible: &Collectible<T>,
+			block_number: T::BlockNumber,
+		) -> Option<BalanceOf<T>> {
+			let scheduled = Escalations::<T>::get(agreement_id)
+				.filter(|scheduled| scheduled.effective_from <= block_number)?;
+			let price_per_block = RentalAgreements::<T>::get(agreement_id)?.price_per_block;
+
+			let price_per_block = match scheduled.escalation {
+				Escalation::PerRenewal(increase) =>
+					price_per_block.saturating_add(increase.mul_floor(price_per_block)),
+				Escalation::NewPrice(price_per_block) => price_per_block,
+			};
+
+			match Self::collection_policy(collectible)
+				.and_then(|policy| policy.maximum_price_per_block)
+			{
+				Some(maximum_price_per_block) => Some(price_per_block.min(maximum_price_per_block)),
+				None => Some(price_per_block),
+			}
+		}
+
+		// The rental policy of the collection a collectible belongs to, if any.
+		fn collection_policy(collectible: &Collectible<T>) -> Option<CollectionPolicyOf<T>> {
+			collectible
+				.collection
+				.and_then(Collections::<T>::get)
+				.map(|collection| collection.policy)
+		}
+
+		// The royalty on `amount` of rent of a collectible that is due to the creator of its
+		// collection. A creator renting out their own items does not pay themselves.
+		fn collection_royalty(
+			collectible_id: CollectibleId,
+			lessor: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Option<(CollectionId, T::AccountId, BalanceOf<T>)> {
+			let collection_id = Collectibles::<T>::get(&collectible_id)?.collection?;
+			let collection = Collections::<T>::get(collection_id)?;
+			let royalty = collection.policy.royalty.mul_floor(amount);
+
+			if royalty.is_zero() || collection.creator == *lessor {
+				return None
+			}
+
+			Some((collection_id, collection.creator, royalty))
+		}
+
+		pub(crate) fn next_agreement_id() -> AgreementId {
+			NextAgreementId::<T>::mutate(|next_agreement_id| {
+				let agreement_id = *next_agreement_id;
+				*next_agreement_id = next_agreement_id.wrapping_add(1);
+				agreement_id
+			})
+		}
+
 		fn unequip_collectible_from_account(account: T::AccountId, collectible_id: CollectibleId) {
 			let mut equiped = AccountEquips::<T>::get(&account).unwrap_or_default();
 			let initial_size = equiped.len().clone();
@@ -746,7 +4346,7 @@ pub mod pallet {
 			}
 		}
 
-		fn fetch_collectible(
+		pub(crate) fn fetch_collectible(
 			collectible_id: CollectibleId,
 		) -> Result<Collectible<T>, DispatchError> {
 			let collectible = Collectibles::<T>::try_get(&collectible_id)
@@ -781,6 +4381,33 @@ pub mod pallet {
 			Ok(())
 		}
 
+		fn ensure_valid_policy(policy: &CollectionPolicyOf<T>) -> Result<(), Error<T>> {
+			if let (Some(minimum), Some(maximum)) =
+				(policy.minimum_price_per_block, policy.maximum_price_per_block)
+			{
+				ensure!(minimum <= maximum, Error::<T>::MinimumMustBeLessThanMaximum);
+			}
+
+			if let (Some(minimum), Some(maximum)) =
+				(policy.minimum_rental_period, policy.maximum_rental_period)
+			{
+				ensure!(minimum <= maximum, Error::<T>::MinimumMustBeLessThanMaximum);
+			}
+
+			Ok(())
+		}
+
+		// Metadata can only be changed by the lessor, and not while the collectible is rented.
+		fn ensure_metadata_editable(
+			user: &T::AccountId,
+			collectible: &Collectible<T>,
+		) -> Result<(), Error<T>> {
+			Self::ensure_user_is_lessor(user, collectible)?;
+			ensure!(collectible.lessee.is_none(), Error::<T>::NotAllowedWhileRented);
+
+			Ok(())
+		}
+
 		fn transfer_funds(
 			from: &T::AccountId,
 			to: &T::AccountId,
@@ -803,10 +4430,29 @@ pub mod pallet {
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(n: T::BlockNumber) -> Weight {
-			Self::do_process_rental_periods(n);
+			let expiry_weight = Self::expire_offers(n);
+			let auction_weight = Self::settle_auctions(n);
+			let rental_weight =
+				Self::do_process_rental_periods(n, T::MaxRentalProcessingWeight::get());
 
-			// TODO: Calculate weight
-			Weight::from_parts(0, 0)
+			expiry_weight.saturating_add(auction_weight).saturating_add(rental_weight)
 		}
+
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::do_process_rental_periods(n, remaining_weight)
+		}
+	}
+}
+
+impl<T: Config> RentalInspect<T::AccountId, T::BlockNumber> for Pallet<T> {
+	type ItemId = CollectibleId;
+
+	fn user_of(item: &Self::ItemId) -> Option<T::AccountId> {
+		Collectibles::<T>::get(item)?.lessee
+	}
+
+	fn user_expires(item: &Self::ItemId) -> Option<T::BlockNumber> {
+		let lessee = Self::user_of(item)?;
+		LesseeCollectibles::<T>::get(&lessee, item).map(|config| config.next_rent_block)
 	}
 }
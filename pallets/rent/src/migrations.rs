@@ -0,0 +1,120 @@
+//! Storage migrations of the rent pallet.
+
+use frame_support::{
+	pallet_prelude::*,
+	sp_runtime::traits::{Saturating, Zero},
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::marker::PhantomData;
+
+use crate::{
+	BalanceOf, Collectible, CollectibleId, Collectibles, Config, LesseeCollectibles, Pallet,
+	PaymentMode, RentalAgreement, RentalAgreements, RentalPeriodConfig, RentalStatus,
+};
+
+/// Migrates from version 0, which predates rental agreements, security deposits and
+/// collections, to version 1.
+pub mod v1 {
+	use super::*;
+
+	/// A collectible as stored in version 0.
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldCollectible<T: Config> {
+		pub collectible_id: CollectibleId,
+		pub price_per_block: Option<BalanceOf<T>>,
+		pub lessor: T::AccountId,
+		pub lessee: Option<T::AccountId>,
+		pub rentable: bool,
+		pub minimum_rental_period: Option<u32>,
+		pub maximum_rental_period: Option<u32>,
+	}
+
+	/// A rental configuration as stored in version 0.
+	#[derive(Encode, Decode)]
+	pub(crate) struct OldRentalPeriodConfig<T: Config> {
+		pub rental_periodic_interval: u32,
+		pub next_rent_block: T::BlockNumber,
+		pub recurring: bool,
+	}
+
+	/// Adds the security deposit and collection of collectibles, which have neither, and records
+	/// a rental agreement for every ongoing rental. Only the current rental period of a rental is
+	/// known, so its agreement starts with it and assumes it was paid upfront at the current price
+	/// per block of the collectible.
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut collectibles = 0u64;
+			Collectibles::<T>::translate::<OldCollectible<T>, _>(|_, old| {
+				collectibles += 1;
+
+				Some(Collectible {
+					collectible_id: old.collectible_id,
+					price_per_block: old.price_per_block,
+					lessor: old.lessor,
+					lessee: old.lessee,
+					rentable: old.rentable,
+					minimum_rental_period: old.minimum_rental_period,
+					maximum_rental_period: old.maximum_rental_period,
+					deposit: None,
+					collection: None,
+				})
+			});
+
+			let mut rentals = 0u64;
+			LesseeCollectibles::<T>::translate::<OldRentalPeriodConfig<T>, _>(
+				|lessee, collectible_id, old| {
+					rentals += 1;
+
+					// The rental of a collectible that no longer exists is dropped
+					let collectible = Collectibles::<T>::get(&collectible_id)?;
+					let price_per_block = collectible.price_per_block.unwrap_or_else(Zero::zero);
+					let period_start =
+						old.next_rent_block.saturating_sub(old.rental_periodic_interval.into());
+					let period_rent =
+						price_per_block.saturating_mul(old.rental_periodic_interval.into());
+					let agreement_id = Pallet::<T>::next_agreement_id();
+
+					RentalAgreements::<T>::insert(
+						agreement_id,
+						RentalAgreement {
+							collectible_id,
+							lessor: collectible.lessor,
+							lessee,
+							start_block: period_start,
+							period_start,
+							period_end: old.next_rent_block,
+							total_paid: period_rent,
+							period_rent,
+							price_per_block,
+							renewals: 0,
+							payment_mode: PaymentMode::Upfront,
+							escrowed: Zero::zero(),
+							settled_until: period_start,
+						},
+					);
+
+					Some(RentalPeriodConfig {
+						agreement_id,
+						rental_periodic_interval: old.rental_periodic_interval,
+						next_rent_block: old.next_rent_block,
+						recurring: old.recurring,
+						status: RentalStatus::Active,
+					})
+				},
+			);
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			// Every rental reads its collectible and the next agreement id, and writes the latter
+			// and its agreement besides its configuration.
+			T::DbWeight::get()
+				.reads_writes(1 + collectibles + rentals * 3, 1 + collectibles + rentals * 3)
+		}
+	}
+}
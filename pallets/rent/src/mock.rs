@@ -1,4 +1,8 @@
-use crate::{self as pallet_rent, Collectibles, LessorCollectibles};
+use crate::{
+	self as pallet_rent, AgreementId, Collectible, CollectibleId, Collectibles, LesseeCollectibles,
+	LessorCollectibles, LinearPricing, NextAgreementId, OnRentalLifecycle, PendingRentals,
+	PricingTier, RentalAgreements, RentalPricing,
+};
 use frame_support::{
 	construct_runtime, parameter_types, sp_io,
 	sp_runtime::{
@@ -6,11 +10,12 @@ use frame_support::{
 		app_crypto::sp_core,
 		generic,
 		traits::{BlakeTwo256, IdentifyAccount, One, Verify},
-		MultiSignature,
+		DispatchError, MultiSignature, Permill,
 	},
 	sp_tracing,
-	traits::{ConstU32, ConstU64, ConstU8, Currency, Hooks},
-	weights::IdentityFee,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Currency, Hooks},
+	weights::{IdentityFee, Weight},
+	PalletId,
 };
 use pallet_balances::AccountData;
 use pallet_transaction_payment::{ConstFeeMultiplier, CurrencyAdapter, Multiplier};
@@ -55,6 +60,12 @@ pub type Balance = u64;
 
 parameter_types! {
 	pub FeeMultiplier: Multiplier = Multiplier::one();
+	pub static MaximumRentablesPerBlock: u32 = 100;
+	pub static MaxRentalProcessingWeight: Weight = Weight::MAX;
+	pub static PaymentGracePeriod: u64 = 5;
+	pub const RentPalletId: PalletId = PalletId(*b"py/rentl");
+	pub static RentalLifecycle: Vec<RentalLifecycleEvent> = vec![];
+	pub static RentDiscount: Permill = Permill::zero();
 }
 
 construct_runtime!(
@@ -69,6 +80,7 @@ construct_runtime!(
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
 		Rent: pallet_rent::{Pallet, Call, Storage, Event<T>},
 		RandomnessCollectiveFlip: pallet_randomness_collective_flip::{Pallet, Storage},
+		Uniques: pallet_uniques::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -104,7 +116,96 @@ impl Config for Test {
 	type Currency = Balances;
 	type CollectionRandomness = RandomnessCollectiveFlip;
 	type MaximumOwned = frame_support::pallet_prelude::ConstU32<100>;
-	type MaximumRentablesPerBlock = frame_support::pallet_prelude::ConstU32<100>;
+	type MaximumRentablesPerBlock = MaximumRentablesPerBlock;
+	type MaximumSchedulingProbes = ConstU32<3>;
+	type MaxRentalProcessingWeight = MaxRentalProcessingWeight;
+	type PaymentGracePeriod = PaymentGracePeriod;
+	type DepositDisputePeriod = ConstU64<10>;
+	type DisputeOrigin = frame_system::EnsureRoot<u64>;
+	type MetadataLimit = ConstU32<32>;
+	type KeyLimit = ConstU32<16>;
+	type ValueLimit = ConstU32<16>;
+	type MaxAttributes = ConstU32<2>;
+	type MetadataDepositBase = ConstU64<100>;
+	type DepositPerByte = ConstU64<10>;
+	type PalletId = RentPalletId;
+	type NftCollectionId = u32;
+	type NftId = u32;
+	type Nfts = Uniques;
+	type MaxPricingTiers = ConstU32<3>;
+	type RentalPricing = DiscountedPricing;
+	type UtilizationWindow = ConstU32<100>;
+	type MaxRentalHistory = ConstU32<3>;
+	type EscalationNoticePeriod = ConstU64<5>;
+	type MaxOffersPerBlock = ConstU32<2>;
+	type MaxAuctionsPerBlock = ConstU32<2>;
+	type MaxReservations = ConstU32<3>;
+	type OnRentalLifecycle = RecordRentalLifecycle;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type WeightInfo = ();
+}
+
+/// Linear pricing with a discount of `RentDiscount`.
+pub struct DiscountedPricing;
+
+impl RentalPricing<Test> for DiscountedPricing {
+	fn rent_price(
+		collectible: &Collectible<Test>,
+		lessee: &u64,
+		blocks: u32,
+		now: u64,
+	) -> Result<u64, DispatchError> {
+		let price =
+			<LinearPricing as RentalPricing<Test>>::rent_price(collectible, lessee, blocks, now)?;
+
+		Ok(price - RentDiscount::get() * price)
+	}
+
+	fn applied_tier(collectible: &Collectible<Test>, blocks: u32) -> Option<PricingTier> {
+		<LinearPricing as RentalPricing<Test>>::applied_tier(collectible, blocks)
+	}
+}
+
+/// A rental lifecycle hook call, recorded by `RecordRentalLifecycle`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RentalLifecycleEvent {
+	Started { collectible: CollectibleId, lessee: u64, expires: u64 },
+	Renewed { collectible: CollectibleId, lessee: u64, expires: u64 },
+	Ended { collectible: CollectibleId, lessee: u64 },
+}
+
+pub struct RecordRentalLifecycle;
+
+impl OnRentalLifecycle<u64, CollectibleId, u64> for RecordRentalLifecycle {
+	fn on_rental_started(collectible: &CollectibleId, lessee: &u64, expires: &u64) {
+		// The hook observes the rental it is called for
+		assert_eq!(Collectibles::<Test>::get(collectible).and_then(|c| c.lessee), Some(*lessee));
+
+		RentalLifecycle::mutate(|events| {
+			events.push(RentalLifecycleEvent::Started {
+				collectible: *collectible,
+				lessee: *lessee,
+				expires: *expires,
+			})
+		});
+	}
+
+	fn on_rental_renewed(collectible: &CollectibleId, lessee: &u64, expires: &u64) {
+		RentalLifecycle::mutate(|events| {
+			events.push(RentalLifecycleEvent::Renewed {
+				collectible: *collectible,
+				lessee: *lessee,
+				expires: *expires,
+			})
+		});
+	}
+
+	fn on_rental_ended(collectible: &CollectibleId, lessee: &u64) {
+		RentalLifecycle::mutate(|events| {
+			events.push(RentalLifecycleEvent::Ended { collectible: *collectible, lessee: *lessee })
+		});
+	}
 }
 
 pub const EXISTENTIAL_DEPOSIT: u64 = 500;
@@ -132,6 +233,27 @@ impl pallet_transaction_payment::Config for Test {
 
 impl pallet_randomness_collective_flip::Config for Test {}
 
+impl pallet_uniques::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<u64>;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+	type Locker = ();
+	type CollectionDeposit = ConstU64<2>;
+	type ItemDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type AttributeDepositBase = ConstU64<1>;
+	type DepositPerByte = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<16>;
+	type ValueLimit = ConstU32<16>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type WeightInfo = ();
+}
+
 impl pallet_timestamp::Config for Test {
 	type Moment = u64;
 	type OnTimestampSet = ();
@@ -205,6 +327,8 @@ pub(crate) fn add_collectible(
 			price_per_block,
 			minimum_rental_period,
 			maximum_rental_period,
+			deposit: None,
+			collection: None,
 		},
 	);
 
@@ -212,3 +336,59 @@ pub(crate) fn add_collectible(
 	lessor_collectibles.try_push(collectible_id).unwrap();
 	LessorCollectibles::<Test>::insert(&lessor, lessor_collectibles);
 }
+
+pub(crate) fn add_rental(
+	collectible_id: [u8; 16],
+	lessee: u64,
+	rental_periodic_interval: u32,
+	next_rent_block: u64,
+	recurring: bool,
+) -> AgreementId {
+	let collectible = Collectibles::<Test>::get(collectible_id).unwrap();
+	let price_per_block = collectible.price_per_block.unwrap_or_default();
+
+	let agreement_id = NextAgreementId::<Test>::mutate(|next_agreement_id| {
+		let agreement_id = *next_agreement_id;
+		*next_agreement_id += 1;
+		agreement_id
+	});
+
+	let period_start = next_rent_block.saturating_sub(rental_periodic_interval.into());
+	let period_rent = price_per_block.saturating_mul(rental_periodic_interval.into());
+	RentalAgreements::<Test>::insert(
+		agreement_id,
+		crate::RentalAgreement {
+			collectible_id,
+			lessor: collectible.lessor,
+			lessee,
+			start_block: System::block_number(),
+			period_start,
+			period_end: next_rent_block,
+			total_paid: period_rent,
+			period_rent,
+			price_per_block,
+			renewals: 0,
+			payment_mode: crate::PaymentMode::Upfront,
+			escrowed: 0,
+			settled_until: period_start,
+		},
+	);
+
+	LesseeCollectibles::<Test>::insert(
+		lessee,
+		collectible_id,
+		crate::RentalPeriodConfig {
+			agreement_id,
+			rental_periodic_interval,
+			next_rent_block,
+			recurring,
+			status: crate::RentalStatus::Active,
+		},
+	);
+
+	PendingRentals::<Test>::mutate(next_rent_block, |pending_rentals| {
+		pending_rentals.try_push((collectible_id, lessee)).unwrap()
+	});
+
+	agreement_id
+}
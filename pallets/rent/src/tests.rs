@@ -1,9 +1,28 @@
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::{self, Permill},
+	storage::unhashed,
+	traits::{
+		tokens::nonfungibles::{Create, Inspect, Mutate, Transfer},
+		ConstU32, Currency, Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion,
+	},
+	weights::Weight,
+	BoundedVec,
+};
 
 use crate::{
-	mock::{self, run_to_block, ExtBuilder, Rent, RuntimeEvent, RuntimeOrigin, System, Test},
-	AccountEquips, Collectibles, Error, Event, LesseeCollectibles, LessorCollectibles,
-	PendingRentals, RentableCollectibles,
+	migrations::v1::{MigrateToV1, OldCollectible, OldRentalPeriodConfig},
+	mock::{
+		self, run_to_block, Balances, ExtBuilder, MaxRentalProcessingWeight,
+		MaximumRentablesPerBlock, PaymentGracePeriod, Rent, RentDiscount, RentalLifecycle,
+		RentalLifecycleEvent, RuntimeEvent, RuntimeOrigin, System, Test, Uniques,
+	},
+	AccountEquips, AuctionEnds, Collectibles, CollectionPolicy, Deposits, DynamicPricing, Error,
+	Escalation, Escalations, Event, LesseeCollectibles, LessorCollectibles, MetadataOf,
+	NextAgreementId, NextCollectionId, NftCollectibles, OfferExpiries, OwedRefunds, PaymentMode,
+	PendingRentals, PricingCurve, PricingTier, RefundPolicy, RentableCollectibles,
+	RentalAgreements, RentalInspect, RentalOffers, RentalProcessingError, RentalStatus,
+	Reservations, SaleListings, WeightInfo,
 };
 
 const COLLECTIBLE_ID: [u8; 16] = [1; 16];
@@ -98,7 +117,8 @@ fn test_set_rentable() {
 	ExtBuilder::default().build_and_execute(|| {
 		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
 
-		Rent::set_rentable(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 100, 10, 30).unwrap();
+		Rent::set_rentable(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 100, 10, 30, Some(500))
+			.unwrap();
 
 		assert_eq!(
 			Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap(),
@@ -110,6 +130,8 @@ fn test_set_rentable() {
 				price_per_block: Some(100),
 				minimum_rental_period: Some(10),
 				maximum_rental_period: Some(30),
+				deposit: Some(500),
+				collection: None,
 			}
 		);
 
@@ -123,7 +145,7 @@ fn test_set_rentable_should_fail_if_not_lessor() {
 		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
 
 		assert_noop!(
-			Rent::set_rentable(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 100, 10, 30),
+			Rent::set_rentable(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 100, 10, 30, None),
 			Error::<Test>::NotLessor
 		);
 	});
@@ -133,7 +155,7 @@ fn test_set_rentable_should_fail_if_not_lessor() {
 fn test_set_rentable_should_fail_if_collectible_does_not_exist() {
 	ExtBuilder::default().build_and_execute(|| {
 		assert_noop!(
-			Rent::set_rentable(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 100, 10, 30),
+			Rent::set_rentable(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 100, 10, 30, None),
 			Error::<Test>::NoCollectible
 		);
 	});
@@ -179,10 +201,12 @@ fn test_rent_non_recurring() {
 		Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, rent_period, false).unwrap();
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentPayed {
+			agreement: 0,
 			lessee: 2,
 			lessor: 1,
 			collectible: COLLECTIBLE_ID,
 			total_rent_price: price_per_block * rent_period as u64,
+			tier: None,
 		}));
 
 		assert_eq!(
@@ -195,6 +219,8 @@ fn test_rent_non_recurring() {
 				price_per_block: Some(100),
 				minimum_rental_period: Some(10),
 				maximum_rental_period: Some(30),
+				deposit: None,
+				collection: None,
 			}
 		);
 
@@ -202,9 +228,11 @@ fn test_rent_non_recurring() {
 			Some(lessee_collectibles) => assert_eq!(
 				lessee_collectibles,
 				crate::RentalPeriodConfig {
+					agreement_id: 0,
 					rental_periodic_interval: 10,
 					next_rent_block: 11,
-					recurring: false
+					recurring: false,
+					status: RentalStatus::Active,
 				}
 			),
 			None => panic!("No collectible"),
@@ -212,6 +240,138 @@ fn test_rent_non_recurring() {
 	});
 }
 
+#[test]
+fn test_rental_agreement_is_kept_for_the_lifetime_of_the_rental() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalAgreementCreated {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			start_block: 1,
+			price_per_block: 100,
+		}));
+
+		assert_eq!(
+			RentalAgreements::<Test>::get(0),
+			Some(crate::RentalAgreement {
+				collectible_id: COLLECTIBLE_ID,
+				lessor: 1,
+				lessee: 2,
+				start_block: 1,
+				period_start: 1,
+				period_end: 11,
+				total_paid: 1000,
+				period_rent: 1000,
+				price_per_block: 100,
+				renewals: 0,
+				payment_mode: PaymentMode::Upfront,
+				escrowed: 0,
+				settled_until: 1,
+			})
+		);
+
+		run_to_block(11);
+
+		let agreement = RentalAgreements::<Test>::get(0).unwrap();
+		assert_eq!((agreement.period_start, agreement.period_end), (11, 21));
+		assert_eq!(agreement.total_paid, 2000);
+		assert_eq!(agreement.renewals, 1);
+
+		assert_ok!(Rent::set_recurring(RuntimeOrigin::signed(2), COLLECTIBLE_ID, false));
+
+		run_to_block(21);
+
+		assert_eq!(RentalAgreements::<Test>::get(0), None);
+		assert_eq!(NextAgreementId::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn test_rent_should_spill_over_to_next_free_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaximumRentablesPerBlock::set(2);
+
+		let collectible_ids: Vec<[u8; 16]> = (1..=5).map(|i| [i; 16]).collect();
+
+		for collectible_id in collectible_ids.iter() {
+			mock::add_collectible(*collectible_id, 1, None, true, Some(100), Some(10), Some(30));
+			assert_ok!(Rent::rent(RuntimeOrigin::signed(2), *collectible_id, 10, false));
+		}
+
+		// Blocks 11 and 12 are full, so the last rental lands in block 13
+		assert_eq!(PendingRentals::<Test>::get(11), vec![([1; 16], 2), ([2; 16], 2)]);
+		assert_eq!(PendingRentals::<Test>::get(12), vec![([3; 16], 2), ([4; 16], 2)]);
+		assert_eq!(PendingRentals::<Test>::get(13), vec![([5; 16], 2)]);
+		assert_eq!(LesseeCollectibles::<Test>::get(2, [5; 16]).unwrap().next_rent_block, 13);
+
+		run_to_block(12);
+		assert_eq!(Collectibles::<Test>::get([4; 16]).unwrap().lessee, None);
+		assert_eq!(Collectibles::<Test>::get([5; 16]).unwrap().lessee, Some(2));
+
+		run_to_block(13);
+
+		for (agreement, collectible_id) in (0..).zip(collectible_ids) {
+			System::assert_has_event(RuntimeEvent::Rent(Event::RentalEnded {
+				agreement,
+				lessor: 1,
+				lessee: 2,
+				collectible: collectible_id,
+			}));
+			assert_eq!(Collectibles::<Test>::get(collectible_id).unwrap().lessee, None);
+			assert_eq!(LesseeCollectibles::<Test>::get(2, collectible_id), None);
+		}
+	});
+}
+
+#[test]
+fn test_rent_should_fail_if_no_block_is_available() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaximumRentablesPerBlock::set(1);
+
+		// The mock probes three blocks, which are filled by the first three rentals
+		for i in 1..=4 {
+			mock::add_collectible([i; 16], 1, None, true, Some(100), Some(10), Some(30));
+		}
+		for i in 1..=3 {
+			assert_ok!(Rent::rent(RuntimeOrigin::signed(2), [i; 16], 10, false));
+		}
+
+		assert_noop!(
+			Rent::rent(RuntimeOrigin::signed(2), [4; 16], 10, false),
+			Error::<Test>::NoAvailableRentalSlot
+		);
+	});
+}
+
+#[test]
+fn test_recurring_rental_should_renew_into_next_free_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		MaximumRentablesPerBlock::set(1);
+
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		mock::add_collectible([2; 16], 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		// Occupy the block in which the recurring rental would renew
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(3), [2; 16], 20, false));
+		assert_eq!(PendingRentals::<Test>::get(21), vec![([2; 16], 3)]);
+
+		run_to_block(11);
+		assert_eq!(PendingRentals::<Test>::get(22), vec![(COLLECTIBLE_ID, 2)]);
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID).unwrap().next_rent_block, 22);
+
+		run_to_block(22);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+		assert_eq!(PendingRentals::<Test>::get(32), vec![(COLLECTIBLE_ID, 2)]);
+	});
+}
+
 #[test]
 fn test_pending_rental_process_ending() {
 	ExtBuilder::default().build_and_execute(|| {
@@ -227,23 +387,12 @@ fn test_pending_rental_process_ending() {
 			Some(30),
 		);
 
-		LesseeCollectibles::<Test>::insert(
-			2,
-			COLLECTIBLE_ID,
-			crate::RentalPeriodConfig {
-				rental_periodic_interval: 10,
-				next_rent_block: 11,
-				recurring: false,
-			},
-		);
-
-		let mut pending_rental = PendingRentals::<Test>::get(11);
-		pending_rental.try_append(&mut vec![(COLLECTIBLE_ID, 2)]).unwrap();
-		PendingRentals::<Test>::insert(11, pending_rental);
+		let agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, false);
 
 		run_to_block(11);
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalEnded {
+			agreement,
 			lessor: 1,
 			lessee: 2,
 			collectible: COLLECTIBLE_ID,
@@ -259,6 +408,8 @@ fn test_pending_rental_process_ending() {
 				price_per_block: Some(price_per_block),
 				minimum_rental_period: Some(10),
 				maximum_rental_period: Some(30),
+				deposit: None,
+				collection: None,
 			}
 		);
 
@@ -285,34 +436,24 @@ fn test_pending_rental_process_recurring() {
 			Some(30),
 		);
 
-		// Insert lessee collectible with recurring rental
-		LesseeCollectibles::<Test>::insert(
-			2,
-			COLLECTIBLE_ID,
-			crate::RentalPeriodConfig {
-				rental_periodic_interval: 10,
-				next_rent_block: 11,
-				recurring: true,
-			},
-		);
-
-		// Insert pending rental to process in block 11
-		let mut pending_rental = PendingRentals::<Test>::get(11);
-		pending_rental.try_append(&mut vec![(COLLECTIBLE_ID, 2)]).unwrap();
-		PendingRentals::<Test>::insert(11, pending_rental);
+		// Insert rental with recurring rental
+		let agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, true);
 
 		run_to_block(11);
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalPeriodAdded {
+			agreement,
 			collectible: COLLECTIBLE_ID,
 			next_rent_block: 21,
 		}));
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentPayed {
+			agreement,
 			lessee: 2,
 			lessor: 1,
 			collectible: COLLECTIBLE_ID,
 			total_rent_price: price_per_block * 10 as u64,
+			tier: None,
 		}));
 
 		// Check collectible is no longer rented by lessee
@@ -326,6 +467,8 @@ fn test_pending_rental_process_recurring() {
 				price_per_block: Some(100),
 				minimum_rental_period: Some(10),
 				maximum_rental_period: Some(30),
+				deposit: None,
+				collection: None,
 			}
 		);
 
@@ -334,9 +477,11 @@ fn test_pending_rental_process_recurring() {
 			Some(lessee_collectibles) => assert_eq!(
 				lessee_collectibles,
 				crate::RentalPeriodConfig {
+					agreement_id: agreement,
 					rental_periodic_interval: 10,
 					next_rent_block: 21,
-					recurring: true
+					recurring: true,
+					status: RentalStatus::Active,
 				}
 			),
 			None => panic!("No collectible"),
@@ -344,38 +489,202 @@ fn test_pending_rental_process_recurring() {
 	});
 }
 
+// The weight charged for processing a pending rental, which may start a reservation.
+fn rental_weight() -> Weight {
+	<() as WeightInfo>::process_rental().max(<() as WeightInfo>::activate_reservation())
+}
+
 #[test]
-fn test_set_recurring_during_ongoing_rental_should_renew_rent() {
+fn test_pending_rental_processing_is_bounded_by_weight() {
 	ExtBuilder::default().build_and_execute(|| {
-		let price_per_block: u64 = 100;
+		let collectible_ids: Vec<[u8; 16]> = (1..=5).map(|i| [i; 16]).collect();
 
-		// Insert collectible with present lessee
-		mock::add_collectible(
-			COLLECTIBLE_ID,
-			1,
-			Some(2),
-			true,
-			Some(price_per_block),
-			Some(10),
-			Some(30),
-		);
+		for collectible_id in collectible_ids.iter() {
+			mock::add_collectible(*collectible_id, 1, Some(2), true, Some(100), Some(10), Some(30));
+			mock::add_rental(*collectible_id, 2, 10, 11, false);
+		}
+
+		// Only leave room for two rentals per block
+		MaxRentalProcessingWeight::set(rental_weight().saturating_mul(2));
+
+		run_to_block(11);
+		assert_eq!(PendingRentals::<Test>::get(11).len(), 3);
+
+		run_to_block(12);
+		assert_eq!(PendingRentals::<Test>::get(11).len(), 1);
+
+		run_to_block(13);
+		assert!(!PendingRentals::<Test>::contains_key(11));
+
+		for collectible_id in collectible_ids {
+			assert_eq!(Collectibles::<Test>::get(collectible_id).unwrap().lessee, None);
+			assert_eq!(LesseeCollectibles::<Test>::get(2, collectible_id), None);
+		}
+	});
+}
+
+#[test]
+fn test_pending_rentals_left_over_are_processed_on_idle() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_ids: Vec<[u8; 16]> = (1..=3).map(|i| [i; 16]).collect();
+
+		for collectible_id in collectible_ids.iter() {
+			mock::add_collectible(*collectible_id, 1, Some(2), true, Some(100), Some(10), Some(30));
+			mock::add_rental(*collectible_id, 2, 10, 11, false);
+		}
+
+		// Only leave room for one rental in `on_initialize`
+		MaxRentalProcessingWeight::set(rental_weight());
+
+		run_to_block(11);
+		assert_eq!(PendingRentals::<Test>::get(11).len(), 2);
+
+		let used_weight = Rent::on_idle(11, Weight::MAX);
+		assert_eq!(used_weight, rental_weight().saturating_mul(2));
+		assert!(!PendingRentals::<Test>::contains_key(11));
+
+		for collectible_id in collectible_ids {
+			assert_eq!(Collectibles::<Test>::get(collectible_id).unwrap().lessee, None);
+		}
+	});
+}
+
+#[test]
+fn test_pending_rental_without_rental_config_should_not_halt_blocks() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, Some(100), Some(10), Some(30));
+		mock::add_collectible([2; 16], 1, Some(3), true, Some(100), Some(10), Some(30));
+
+		// No rental configuration for the first collectible
+		PendingRentals::<Test>::mutate(11, |pending_rentals| {
+			pending_rentals.try_push((COLLECTIBLE_ID, 2)).unwrap()
+		});
+		let agreement = mock::add_rental([2; 16], 3, 10, 11, false);
+
+		run_to_block(12);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalProcessingFailed {
+			agreement: None,
+			collectible: COLLECTIBLE_ID,
+			reason: RentalProcessingError::MissingRentalConfig,
+		}));
+
+		// The inconsistent collectible is freed and the other rental still ends
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalEnded {
+			agreement,
+			lessor: 1,
+			lessee: 3,
+			collectible: [2; 16],
+		}));
+		assert!(!PendingRentals::<Test>::contains_key(11));
+	});
+}
 
-		// Insert lessee collectible without reccuring rental
+#[test]
+fn test_pending_rental_of_burned_collectible_should_be_cleaned_up() {
+	ExtBuilder::default().build_and_execute(|| {
 		LesseeCollectibles::<Test>::insert(
 			2,
 			COLLECTIBLE_ID,
 			crate::RentalPeriodConfig {
+				agreement_id: 0,
 				rental_periodic_interval: 10,
 				next_rent_block: 11,
-				recurring: false,
+				recurring: true,
+				status: RentalStatus::Active,
 			},
 		);
+		AccountEquips::<Test>::insert(
+			2,
+			frame_support::BoundedVec::truncate_from(vec![COLLECTIBLE_ID]),
+		);
 
-		// Insert pending rental to process in block 11
 		let mut pending_rental = PendingRentals::<Test>::get(11);
 		pending_rental.try_append(&mut vec![(COLLECTIBLE_ID, 2)]).unwrap();
 		PendingRentals::<Test>::insert(11, pending_rental);
 
+		run_to_block(12);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalProcessingFailed {
+			agreement: Some(0),
+			collectible: COLLECTIBLE_ID,
+			reason: RentalProcessingError::MissingCollectible,
+		}));
+
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID), None);
+		assert_eq!(AccountEquips::<Test>::get(2).unwrap_or_default(), vec![]);
+		assert!(PendingRentals::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn test_recurring_rental_without_price_should_end() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, None, Some(10), Some(30));
+
+		let agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, true);
+
+		run_to_block(12);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalProcessingFailed {
+			agreement: Some(agreement),
+			collectible: COLLECTIBLE_ID,
+			reason: RentalProcessingError::MissingPrice,
+		}));
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID), None);
+	});
+}
+
+#[test]
+fn test_pending_rental_of_other_lessee_should_not_touch_current_rental() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(3), true, Some(100), Some(10), Some(30));
+
+		// The rental of lessee 2 ended without being unscheduled
+		let stale_agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, false);
+		mock::add_rental(COLLECTIBLE_ID, 3, 10, 21, false);
+		RentalLifecycle::set(vec![]);
+
+		run_to_block(12);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalProcessingFailed {
+			agreement: Some(stale_agreement),
+			collectible: COLLECTIBLE_ID,
+			reason: RentalProcessingError::LesseeMismatch,
+		}));
+
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID), None);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(3));
+		assert!(LesseeCollectibles::<Test>::get(3, COLLECTIBLE_ID).is_some());
+		assert_eq!(
+			RentalLifecycle::get(),
+			vec![RentalLifecycleEvent::Ended { collectible: COLLECTIBLE_ID, lessee: 2 }]
+		);
+	});
+}
+
+#[test]
+fn test_set_recurring_during_ongoing_rental_should_renew_rent() {
+	ExtBuilder::default().build_and_execute(|| {
+		let price_per_block: u64 = 100;
+
+		// Insert collectible with present lessee
+		mock::add_collectible(
+			COLLECTIBLE_ID,
+			1,
+			Some(2),
+			true,
+			Some(price_per_block),
+			Some(10),
+			Some(30),
+		);
+
+		// Insert rental without recurring rental
+		let agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, false);
+
 		run_to_block(5);
 
 		// before reaching block 11, set recurring rental
@@ -384,15 +693,18 @@ fn test_set_recurring_during_ongoing_rental_should_renew_rent() {
 		run_to_block(11);
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalPeriodAdded {
+			agreement,
 			collectible: COLLECTIBLE_ID,
 			next_rent_block: 21,
 		}));
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentPayed {
+			agreement,
 			lessee: 2,
 			lessor: 1,
 			collectible: COLLECTIBLE_ID,
 			total_rent_price: price_per_block * 10 as u64,
+			tier: None,
 		}));
 
 		// Check collectible is no longer rented by lessee
@@ -406,6 +718,8 @@ fn test_set_recurring_during_ongoing_rental_should_renew_rent() {
 				price_per_block: Some(100),
 				minimum_rental_period: Some(10),
 				maximum_rental_period: Some(30),
+				deposit: None,
+				collection: None,
 			}
 		);
 	});
@@ -427,21 +741,8 @@ fn test_set_recurring_during_ongoing_rental_should_not_renew_rent() {
 			Some(30),
 		);
 
-		// Insert lessee collectible with reccuring rental
-		LesseeCollectibles::<Test>::insert(
-			2,
-			COLLECTIBLE_ID,
-			crate::RentalPeriodConfig {
-				rental_periodic_interval: 10,
-				next_rent_block: 11,
-				recurring: true,
-			},
-		);
-
-		// Insert pending rental to process in block 11
-		let mut pending_rental = PendingRentals::<Test>::get(11);
-		pending_rental.try_append(&mut vec![(COLLECTIBLE_ID, 2)]).unwrap();
-		PendingRentals::<Test>::insert(11, pending_rental);
+		// Insert rental with recurring rental
+		let agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, true);
 
 		run_to_block(5);
 
@@ -454,6 +755,7 @@ fn test_set_recurring_during_ongoing_rental_should_not_renew_rent() {
 		run_to_block(11);
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalEnded {
+			agreement,
 			lessor: 1,
 			lessee: 2,
 			collectible: COLLECTIBLE_ID,
@@ -470,6 +772,8 @@ fn test_set_recurring_during_ongoing_rental_should_not_renew_rent() {
 				price_per_block: Some(100),
 				minimum_rental_period: Some(10),
 				maximum_rental_period: Some(30),
+				deposit: None,
+				collection: None,
 			}
 		);
 
@@ -497,21 +801,8 @@ fn test_extend_rent() {
 			Some(30),
 		);
 
-		// Insert lessee collectible without reccuring rental
-		LesseeCollectibles::<Test>::insert(
-			2,
-			COLLECTIBLE_ID,
-			crate::RentalPeriodConfig {
-				rental_periodic_interval: 10,
-				next_rent_block: 11,
-				recurring: false,
-			},
-		);
-
-		// Insert pending rental to process in block 11
-		let mut pending_rental = PendingRentals::<Test>::get(11);
-		pending_rental.try_append(&mut vec![(COLLECTIBLE_ID, 2)]).unwrap();
-		PendingRentals::<Test>::insert(11, pending_rental);
+		// Insert rental without recurring rental
+		let agreement = mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, false);
 
 		run_to_block(5);
 
@@ -519,6 +810,7 @@ fn test_extend_rent() {
 		assert_ok!(Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 3));
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalExtended {
+			agreement,
 			lessor: 1,
 			lessee: 2,
 			collectible: COLLECTIBLE_ID,
@@ -526,11 +818,13 @@ fn test_extend_rent() {
 		}));
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalPeriodAdded {
+			agreement,
 			collectible: COLLECTIBLE_ID,
 			next_rent_block: 14,
 		}));
 
 		System::assert_has_event(RuntimeEvent::Rent(Event::RentalPeriodRemoved {
+			agreement,
 			collectible: COLLECTIBLE_ID,
 			at_block: 11,
 		}));
@@ -558,29 +852,50 @@ fn test_extend_rent_should_fail_if_exceeds_maximum_rental_period() {
 			Some(30),
 		);
 
-		// Insert lessee collectible without reccuring rental
-		LesseeCollectibles::<Test>::insert(
-			2,
-			COLLECTIBLE_ID,
-			crate::RentalPeriodConfig {
-				rental_periodic_interval: 10,
-				next_rent_block: 11,
-				recurring: false,
-			},
-		);
-
-		// Insert pending rental to process in block 11
-		let mut pending_rental = PendingRentals::<Test>::get(11);
-		pending_rental.try_append(&mut vec![(COLLECTIBLE_ID, 2)]).unwrap();
-		PendingRentals::<Test>::insert(11, pending_rental);
+		// Insert rental without recurring rental
+		mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, false);
 
 		run_to_block(5);
 
-		// before reaching block 11, extend rental
+		// before reaching block 11, extend rental past 30 blocks
 		assert_noop!(
-			Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 20),
+			Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 21),
 			Error::<Test>::RentalPeriodTooLong
 		);
+
+		// extending up to the maximum rental period is allowed
+		assert_ok!(Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 20));
+		assert_eq!(PendingRentals::<Test>::get(31), vec![(COLLECTIBLE_ID, 2)]);
+	});
+}
+
+#[test]
+fn test_extend_rent_should_not_depend_on_current_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		run_to_block(100);
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+		assert_ok!(Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 15));
+
+		let agreement = RentalAgreements::<Test>::get(0).unwrap();
+		assert_eq!((agreement.period_start, agreement.period_end), (100, 125));
+		assert_eq!(agreement.total_paid, 2500);
+		assert_eq!(PendingRentals::<Test>::get(125), vec![(COLLECTIBLE_ID, 2)]);
+	});
+}
+
+#[test]
+fn test_extend_rent_should_fail_on_price_overflow() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, Some(u64::MAX / 2), Some(1), None);
+		mock::add_rental(COLLECTIBLE_ID, 2, 1, 2, false);
+
+		assert_noop!(
+			Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 3),
+			Error::<Test>::Overflow
+		);
 	});
 }
 
@@ -710,3 +1025,2095 @@ fn test_should_not_equip_unrented_collectible_as_lessee() {
 		);
 	});
 }
+
+#[test]
+fn test_end_rental() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		assert_ok!(Rent::equip_collectible(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+		run_to_block(5);
+
+		assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalEnded {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+		}));
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID), None);
+		assert_eq!(RentalAgreements::<Test>::get(0), None);
+		assert_eq!(PendingRentals::<Test>::get(11), vec![]);
+		assert_eq!(AccountEquips::<Test>::get(2).unwrap_or_default(), vec![]);
+
+		// Without a refund policy nothing is paid back
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+
+		// The recurring rental is not renewed anymore
+		run_to_block(11);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+	});
+}
+
+#[test]
+fn test_end_rental_should_refund_unused_blocks() {
+	for (policy, refund) in
+		[(RefundPolicy::ProRata, 600), (RefundPolicy::Partial(Permill::from_percent(50)), 300)]
+	{
+		ExtBuilder::default().build_and_execute(|| {
+			mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+			assert_ok!(Rent::set_refund_policy(RuntimeOrigin::signed(1), COLLECTIBLE_ID, policy));
+			assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+			run_to_block(5);
+
+			assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+			System::assert_has_event(RuntimeEvent::Rent(Event::RentRefunded {
+				agreement: 0,
+				lessor: 1,
+				lessee: 2,
+				collectible: COLLECTIBLE_ID,
+				amount: refund,
+			}));
+
+			assert_eq!(Balances::free_balance(2), 1000000000 - 1000 + refund);
+			assert_eq!(Balances::free_balance(1), 1000000000 + 1000 - refund);
+		});
+	}
+}
+
+#[test]
+fn test_end_rental_records_refund_lessor_cannot_pay() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::set_refund_policy(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			RefundPolicy::ProRata
+		));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+		Balances::make_free_balance_be(&1, 100);
+
+		run_to_block(5);
+
+		// The rental ends even though the lessor cannot pay the refund
+		assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RefundOwed {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 600,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(OwedRefunds::<Test>::get(1, 2), 600);
+
+		assert_noop!(
+			Rent::claim_refund(RuntimeOrigin::signed(2), 1),
+			Error::<Test>::LessorCannotRefund
+		);
+		assert_noop!(Rent::claim_refund(RuntimeOrigin::signed(3), 1), Error::<Test>::NoRefundOwed);
+
+		Balances::make_free_balance_be(&1, 1000);
+		assert_ok!(Rent::claim_refund(RuntimeOrigin::signed(2), 1));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::OwedRefundPaid {
+			lessor: 1,
+			lessee: 2,
+			amount: 600,
+		}));
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 + 600);
+		assert_eq!(OwedRefunds::<Test>::get(1, 2), 0);
+	});
+}
+
+#[test]
+fn test_end_rental_refunds_only_lessor_share_of_rent() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), collectible_id, 3, false));
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(3), collectible_id, 100, 10, 30, None));
+		assert_ok!(Rent::set_refund_policy(
+			RuntimeOrigin::signed(3),
+			collectible_id,
+			RefundPolicy::ProRata
+		));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), collectible_id, 10, false));
+
+		run_to_block(5);
+
+		assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), collectible_id));
+
+		// The creator keeps the royalty on the unused rent
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentRefunded {
+			agreement: 0,
+			lessor: 3,
+			lessee: 2,
+			collectible: collectible_id,
+			amount: 540,
+		}));
+		assert_eq!(Balances::free_balance(1), 1000000000 + 100);
+		assert_eq!(Balances::free_balance(3), 1000000000 + 900 - 540);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 + 540);
+	});
+}
+
+#[test]
+fn test_end_rental_should_fail_if_not_lessee() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, Some(100), Some(10), Some(30));
+		mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, false);
+
+		assert_noop!(
+			Rent::end_rental(RuntimeOrigin::signed(3), COLLECTIBLE_ID),
+			Error::<Test>::NotLessee
+		);
+	});
+}
+
+#[test]
+fn test_set_refund_policy_should_fail_while_rented() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, Some(100), Some(10), Some(30));
+
+		assert_noop!(
+			Rent::set_refund_policy(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				RefundPolicy::ProRata
+			),
+			Error::<Test>::NotAllowedWhileRented
+		);
+	});
+}
+
+#[test]
+fn test_escrowed_rent_is_released_as_blocks_elapse() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::set_payment_mode(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			PaymentMode::Escrow
+		));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentEscrowed {
+			agreement: 0,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 1000,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 1000);
+		assert_eq!(Balances::free_balance(1), 1000000000);
+
+		run_to_block(5);
+
+		assert_ok!(Rent::claim_rent(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentReleased {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 400,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 600);
+		assert_eq!(Balances::free_balance(1), 1000000000 + 400);
+
+		// The rest is released when the rental period ends
+		run_to_block(11);
+
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+	});
+}
+
+#[test]
+fn test_escrowed_recurring_rental_should_escrow_next_period() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::set_payment_mode(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			PaymentMode::Escrow
+		));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000);
+		assert_eq!(Balances::reserved_balance(2), 1000);
+
+		let agreement = RentalAgreements::<Test>::get(0).unwrap();
+		assert_eq!(agreement.escrowed, 1000);
+		assert_eq!(agreement.settled_until, 11);
+	});
+}
+
+#[test]
+fn test_end_rental_should_settle_escrowed_rent() {
+	for (policy, lessor_income) in [(RefundPolicy::NoRefund, 1000), (RefundPolicy::ProRata, 400)] {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+			assert_ok!(Rent::set_payment_mode(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				PaymentMode::Escrow
+			));
+			assert_ok!(Rent::set_refund_policy(RuntimeOrigin::signed(1), COLLECTIBLE_ID, policy));
+			assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+			run_to_block(5);
+
+			assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+			assert_eq!(Balances::reserved_balance(2), 0);
+			assert_eq!(Balances::free_balance(1), 1000000000 + lessor_income);
+			assert_eq!(Balances::free_balance(2), 1000000000 - lessor_income);
+		});
+	}
+}
+
+#[test]
+fn test_claim_rent_should_fail_if_rent_is_not_escrowed() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+		assert_noop!(
+			Rent::claim_rent(RuntimeOrigin::signed(1), COLLECTIBLE_ID),
+			Error::<Test>::NoEscrowedRent
+		);
+	});
+}
+
+// Rents out a collectible with a security deposit of 500 to account 2.
+fn rent_with_deposit() {
+	mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+	assert_ok!(Rent::set_rentable(
+		RuntimeOrigin::signed(1),
+		COLLECTIBLE_ID,
+		100,
+		10,
+		30,
+		Some(500)
+	));
+	assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+}
+
+#[test]
+fn test_deposit_is_returned_when_rental_ends() {
+	ExtBuilder::default().build_and_execute(|| {
+		rent_with_deposit();
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::DepositReserved {
+			agreement: 0,
+			lessee: 2,
+			amount: 500,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 500);
+
+		run_to_block(11);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::DepositReleased {
+			agreement: 0,
+			lessee: 2,
+			amount: 500,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(Deposits::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn test_undisputed_deposit_claim_is_paid_to_lessor() {
+	ExtBuilder::default().build_and_execute(|| {
+		rent_with_deposit();
+
+		assert_ok!(Rent::claim_deposit(RuntimeOrigin::signed(1), 0, 200));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::DepositClaimed {
+			agreement: 0,
+			lessor: 1,
+			amount: 200,
+			disputable_until: 11,
+		}));
+
+		// The open claim keeps the deposit reserved after the rental ended
+		run_to_block(11);
+		assert_eq!(Balances::reserved_balance(2), 500);
+
+		assert_noop!(
+			Rent::settle_deposit_claim(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::DisputePeriodNotOver
+		);
+
+		run_to_block(12);
+
+		assert_ok!(Rent::settle_deposit_claim(RuntimeOrigin::signed(3), 0));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::DepositSlashed {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			amount: 200,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 200);
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000 + 200);
+		assert!(Deposits::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn test_disputed_deposit_claim_is_resolved_by_dispute_origin() {
+	ExtBuilder::default().build_and_execute(|| {
+		rent_with_deposit();
+
+		assert_ok!(Rent::claim_deposit(RuntimeOrigin::signed(1), 0, 400));
+		assert_ok!(Rent::dispute_deposit_claim(RuntimeOrigin::signed(2), 0));
+
+		run_to_block(12);
+
+		assert_noop!(
+			Rent::settle_deposit_claim(RuntimeOrigin::signed(1), 0),
+			Error::<Test>::ClaimDisputed
+		);
+		assert_noop!(
+			Rent::resolve_deposit_dispute(RuntimeOrigin::signed(1), 0, 100),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Rent::resolve_deposit_dispute(RuntimeOrigin::root(), 0, 100));
+
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 100);
+		assert!(Deposits::<Test>::get(0).is_none());
+	});
+}
+
+#[test]
+fn test_deposit_claim_should_fail_if_exceeding_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		rent_with_deposit();
+
+		assert_noop!(
+			Rent::claim_deposit(RuntimeOrigin::signed(2), 0, 100),
+			Error::<Test>::NotLessor
+		);
+		assert_noop!(
+			Rent::claim_deposit(RuntimeOrigin::signed(1), 0, 501),
+			Error::<Test>::ClaimExceedsDeposit
+		);
+	});
+}
+
+#[test]
+fn test_deposit_claim_should_not_be_disputed_after_dispute_period() {
+	ExtBuilder::default().build_and_execute(|| {
+		rent_with_deposit();
+
+		assert_ok!(Rent::claim_deposit(RuntimeOrigin::signed(1), 0, 100));
+
+		run_to_block(12);
+
+		assert_noop!(
+			Rent::dispute_deposit_claim(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::DisputePeriodOver
+		);
+	});
+}
+
+#[test]
+fn test_failed_recurring_payment_can_be_paid_during_grace_period() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		Balances::make_free_balance_be(&2, 600);
+
+		run_to_block(11);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentPaymentOverdue {
+			agreement: 0,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 1000,
+			deadline: 16,
+		}));
+
+		// The lessee keeps the collectible during the grace period
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+		let rental = LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID).unwrap();
+		assert_eq!(rental.status, RentalStatus::PaymentOverdue { since: 11 });
+		assert_eq!(rental.next_rent_block, 16);
+
+		run_to_block(13);
+
+		assert_noop!(
+			Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 5),
+			Error::<Test>::PaymentOverdue
+		);
+
+		Balances::make_free_balance_be(&2, 3000);
+		RentalLifecycle::set(vec![]);
+		assert_ok!(Rent::pay_overdue(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::OverdueRentPaid {
+			agreement: 0,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 1000,
+		}));
+
+		// The paid period started when the rent was due
+		let rental = LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID).unwrap();
+		assert_eq!(rental.status, RentalStatus::Active);
+		assert_eq!(rental.next_rent_block, 21);
+		assert_eq!(PendingRentals::<Test>::get(16), vec![]);
+		assert_eq!(PendingRentals::<Test>::get(21), vec![(COLLECTIBLE_ID, 2)]);
+		assert_eq!(
+			RentalLifecycle::get(),
+			vec![RentalLifecycleEvent::Renewed {
+				collectible: COLLECTIBLE_ID,
+				lessee: 2,
+				expires: 21
+			}]
+		);
+
+		run_to_block(21);
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+		assert_eq!(Balances::free_balance(2), 1000);
+	});
+}
+
+#[test]
+fn test_overdue_rental_is_terminated_after_grace_period() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		Balances::make_free_balance_be(&2, 600);
+
+		run_to_block(15);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+
+		run_to_block(16);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::ErrorTransferingRent {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID), None);
+	});
+}
+
+#[test]
+fn test_failed_recurring_payment_without_grace_period_should_end_rental() {
+	ExtBuilder::default().build_and_execute(|| {
+		PaymentGracePeriod::set(0);
+
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		Balances::make_free_balance_be(&2, 600);
+
+		run_to_block(11);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::ErrorTransferingRent {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+	});
+}
+
+#[test]
+fn test_pay_overdue_should_fail_if_rent_is_not_overdue() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, Some(100), Some(10), Some(30));
+		mock::add_rental(COLLECTIBLE_ID, 2, 10, 11, true);
+
+		assert_noop!(
+			Rent::pay_overdue(RuntimeOrigin::signed(2), COLLECTIBLE_ID),
+			Error::<Test>::NoPaymentOverdue
+		);
+	});
+}
+
+#[test]
+fn test_transfer() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 3, false));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::TransferSucceeded {
+			from: 1,
+			to: 3,
+			collectible: COLLECTIBLE_ID,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessor, 3);
+		assert!(LessorCollectibles::<Test>::get(1).unwrap().is_empty());
+		assert_eq!(LessorCollectibles::<Test>::get(3).unwrap().into_inner(), vec![COLLECTIBLE_ID]);
+	});
+}
+
+#[test]
+fn test_transfer_should_fail_while_rented() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+		assert_noop!(
+			Rent::transfer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 3, false),
+			Error::<Test>::NotAllowedWhileRented
+		);
+	});
+}
+
+#[test]
+fn test_transfer_subject_to_lease_pays_future_rent_to_new_lessor() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 3, true));
+
+		let collectible = Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap();
+		assert_eq!(collectible.lessor, 3);
+		assert_eq!(collectible.lessee, Some(2));
+		assert_eq!(RentalAgreements::<Test>::get(0).unwrap().lessor, 3);
+
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000);
+		assert_eq!(Balances::free_balance(3), 1000000000 + 1000);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 2000);
+	});
+}
+
+#[test]
+fn test_transfer_subject_to_lease_releases_elapsed_escrow_to_previous_lessor() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::set_payment_mode(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			PaymentMode::Escrow
+		));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+		run_to_block(5);
+
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 3, true));
+
+		assert_eq!(Balances::free_balance(1), 1000000000 + 400);
+
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(3), 1000000000 + 600);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn test_transfer_should_fail_if_recipient_owns_too_many_collectibles() {
+	ExtBuilder::default().build_and_execute(|| {
+		for i in 0..100 {
+			mock::add_collectible([i; 16], 3, None, false, None, None, None);
+		}
+		mock::add_collectible([200; 16], 1, None, false, None, None, None);
+
+		assert_noop!(
+			Rent::transfer(RuntimeOrigin::signed(1), [200; 16], 3, false),
+			Error::<Test>::TooManyCollectiblesOwned
+		);
+	});
+}
+
+#[test]
+fn test_buy() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::list_for_sale(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 5000, false));
+		assert_ok!(Rent::buy(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 5000));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::CollectibleSold {
+			collectible: COLLECTIBLE_ID,
+			seller: 1,
+			buyer: 3,
+			price: 5000,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessor, 3);
+		assert_eq!(LessorCollectibles::<Test>::get(3).unwrap().into_inner(), vec![COLLECTIBLE_ID]);
+		assert!(SaleListings::<Test>::get(COLLECTIBLE_ID).is_none());
+		assert_eq!(Balances::free_balance(1), 1000000000 + 5000);
+		assert_eq!(Balances::free_balance(3), 1000000000 - 5000);
+	});
+}
+
+#[test]
+fn test_buy_should_fail_if_price_is_too_high() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::list_for_sale(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 5000, false));
+
+		assert_noop!(
+			Rent::buy(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 4999),
+			Error::<Test>::PriceTooHigh
+		);
+	});
+}
+
+#[test]
+fn test_buy_should_fail_if_not_for_sale() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::list_for_sale(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 5000, false));
+		assert_ok!(Rent::unlist_from_sale(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+
+		assert_noop!(
+			Rent::buy(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 5000),
+			Error::<Test>::NotForSale
+		);
+	});
+}
+
+#[test]
+fn test_buy_rented_collectible_waits_for_rental_to_end() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+		assert_ok!(Rent::list_for_sale(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 5000, false));
+
+		assert_noop!(
+			Rent::buy(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 5000),
+			Error::<Test>::NotAllowedWhileRented
+		);
+
+		run_to_block(11);
+
+		assert_ok!(Rent::buy(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 5000));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessor, 3);
+	});
+}
+
+#[test]
+fn test_buy_rented_collectible_subject_to_lease() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		assert_ok!(Rent::list_for_sale(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 5000, true));
+
+		assert_ok!(Rent::buy(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 5000));
+
+		let collectible = Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap();
+		assert_eq!(collectible.lessor, 3);
+		assert_eq!(collectible.lessee, Some(2));
+
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000 + 5000);
+		assert_eq!(Balances::free_balance(3), 1000000000 - 5000 + 1000);
+	});
+}
+
+fn bounded<S: Get<u32>>(bytes: &[u8]) -> BoundedVec<u8, S> {
+	bytes.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn test_set_metadata_reserves_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::set_metadata(RuntimeOrigin::signed(1), COLLECTIBLE_ID, bounded(b"ipfs")));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::MetadataSet {
+			collectible: COLLECTIBLE_ID,
+			data: bounded(b"ipfs"),
+		}));
+		// Base deposit of 100 plus 10 per byte
+		assert_eq!(Balances::reserved_balance(1), 140);
+
+		assert_ok!(Rent::set_attribute(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			bounded(b"level"),
+			bounded(b"10")
+		));
+		assert_eq!(Balances::reserved_balance(1), 210);
+
+		let metadata = MetadataOf::<Test>::get(COLLECTIBLE_ID).unwrap();
+		assert_eq!(metadata.data.into_inner(), b"ipfs".to_vec());
+		assert_eq!(metadata.attributes.into_inner(), vec![(bounded(b"level"), bounded(b"10"))]);
+
+		assert_ok!(Rent::clear_attribute(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			bounded(b"level")
+		));
+		assert_eq!(Balances::reserved_balance(1), 140);
+
+		assert_ok!(Rent::clear_metadata(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(MetadataOf::<Test>::get(COLLECTIBLE_ID).is_none());
+	});
+}
+
+#[test]
+fn test_set_attribute_should_fail_with_too_many_attributes() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::set_attribute(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			bounded(b"a"),
+			bounded(b"1")
+		));
+		assert_ok!(Rent::set_attribute(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			bounded(b"b"),
+			bounded(b"1")
+		));
+		// Overwriting an existing attribute does not take room
+		assert_ok!(Rent::set_attribute(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			bounded(b"a"),
+			bounded(b"2")
+		));
+
+		assert_noop!(
+			Rent::set_attribute(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				bounded(b"c"),
+				bounded(b"1")
+			),
+			Error::<Test>::TooManyAttributes
+		);
+	});
+}
+
+#[test]
+fn test_set_metadata_should_fail_while_rented() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, Some(2), true, Some(100), Some(10), Some(30));
+
+		assert_noop!(
+			Rent::set_metadata(RuntimeOrigin::signed(1), COLLECTIBLE_ID, bounded(b"ipfs")),
+			Error::<Test>::NotAllowedWhileRented
+		);
+		assert_noop!(
+			Rent::set_metadata(RuntimeOrigin::signed(2), COLLECTIBLE_ID, bounded(b"ipfs")),
+			Error::<Test>::NotLessor
+		);
+	});
+}
+
+#[test]
+fn test_metadata_deposit_moves_to_new_lessor() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::set_metadata(RuntimeOrigin::signed(1), COLLECTIBLE_ID, bounded(b"ipfs")));
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 3, false));
+		assert_ok!(Rent::set_metadata(RuntimeOrigin::signed(3), COLLECTIBLE_ID, bounded(b"uri")));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(3), 130);
+
+		assert_ok!(Rent::burn(RuntimeOrigin::signed(3), COLLECTIBLE_ID));
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+fn collection_policy() -> CollectionPolicy<u64> {
+	CollectionPolicy {
+		rentable: true,
+		minimum_price_per_block: Some(50),
+		maximum_price_per_block: Some(200),
+		minimum_rental_period: Some(5),
+		maximum_rental_period: Some(50),
+		royalty: Permill::from_percent(10),
+	}
+}
+
+// Creates a collection with `policy` as account 1 and mints an item into it.
+fn mint_into_collection(policy: CollectionPolicy<u64>) -> [u8; 16] {
+	assert_ok!(Rent::create_collection(RuntimeOrigin::signed(1), policy));
+	assert_ok!(Rent::mint_into_collection(RuntimeOrigin::signed(1), 0));
+	LessorCollectibles::<Test>::get(1).unwrap()[0]
+}
+
+#[test]
+fn test_mint_into_collection() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::CollectionCreated {
+			collection: 0,
+			creator: 1,
+		}));
+		assert_eq!(Collectibles::<Test>::get(collectible_id).unwrap().collection, Some(0));
+
+		assert_noop!(
+			Rent::mint_into_collection(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotCollectionCreator
+		);
+		assert_noop!(
+			Rent::mint_into_collection(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::NoCollection
+		);
+	});
+}
+
+#[test]
+fn test_set_rentable_should_follow_collection_policy() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+
+		assert_noop!(
+			Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 300, 10, 30, None),
+			Error::<Test>::PriceOutsideCollectionPolicy
+		);
+		assert_noop!(
+			Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 100, 1, 30, None),
+			Error::<Test>::RentalPeriodOutsideCollectionPolicy
+		);
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 100, 10, 30, None));
+
+		assert_ok!(Rent::set_collection_policy(
+			RuntimeOrigin::signed(1),
+			0,
+			CollectionPolicy { rentable: false, ..collection_policy() }
+		));
+
+		assert_noop!(
+			Rent::rent(RuntimeOrigin::signed(2), collectible_id, 10, false),
+			Error::<Test>::CollectionNotRentable
+		);
+		assert_noop!(
+			Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 100, 10, 30, None),
+			Error::<Test>::CollectionNotRentable
+		);
+	});
+}
+
+#[test]
+fn test_rent_should_follow_changed_collection_policy() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 100, 10, 30, None));
+
+		assert_ok!(Rent::set_collection_policy(
+			RuntimeOrigin::signed(1),
+			0,
+			CollectionPolicy { maximum_rental_period: Some(20), ..collection_policy() }
+		));
+
+		assert_noop!(
+			Rent::rent(RuntimeOrigin::signed(2), collectible_id, 25, false),
+			Error::<Test>::RentalPeriodOutsideCollectionPolicy
+		);
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), collectible_id, 20, false));
+	});
+}
+
+#[test]
+fn test_rent_pays_royalty_to_collection_creator() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), collectible_id, 3, false));
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(3), collectible_id, 100, 10, 30, None));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), collectible_id, 10, false));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RoyaltyPaid {
+			agreement: 0,
+			collection: 0,
+			creator: 1,
+			collectible: collectible_id,
+			amount: 100,
+		}));
+		assert_eq!(Balances::free_balance(1), 1000000000 + 100);
+		assert_eq!(Balances::free_balance(3), 1000000000 + 900);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+	});
+}
+
+#[test]
+fn test_create_collection_should_fail_with_invalid_policy() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_noop!(
+			Rent::create_collection(
+				RuntimeOrigin::signed(1),
+				CollectionPolicy { minimum_rental_period: Some(60), ..collection_policy() }
+			),
+			Error::<Test>::MinimumMustBeLessThanMaximum
+		);
+	});
+}
+
+#[test]
+fn test_nonfungibles_inspect() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::set_metadata(RuntimeOrigin::signed(1), COLLECTIBLE_ID, bounded(b"ipfs")));
+		assert_ok!(Rent::set_attribute(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			bounded(b"level"),
+			bounded(b"10")
+		));
+
+		assert_eq!(<Rent as Inspect<u64>>::owner(&None, &COLLECTIBLE_ID), Some(1));
+		assert_eq!(<Rent as Inspect<u64>>::owner(&Some(0), &COLLECTIBLE_ID), None);
+		assert_eq!(
+			<Rent as Inspect<u64>>::attribute(&None, &COLLECTIBLE_ID, b""),
+			Some(b"ipfs".to_vec())
+		);
+		assert_eq!(
+			<Rent as Inspect<u64>>::attribute(&None, &COLLECTIBLE_ID, b"level"),
+			Some(b"10".to_vec())
+		);
+		assert_eq!(<Rent as Inspect<u64>>::attribute(&None, &COLLECTIBLE_ID, b"rank"), None);
+		assert!(<Rent as Inspect<u64>>::can_transfer(&None, &COLLECTIBLE_ID));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+		assert!(!<Rent as Inspect<u64>>::can_transfer(&None, &COLLECTIBLE_ID));
+	});
+}
+
+#[test]
+fn test_nonfungibles_transfer_and_burn_should_fail_while_rented() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false));
+
+		assert_noop!(
+			<Rent as Transfer<u64>>::transfer(&None, &COLLECTIBLE_ID, &3),
+			Error::<Test>::NotAllowedWhileRented
+		);
+		assert_noop!(
+			<Rent as Mutate<u64>>::burn(&None, &COLLECTIBLE_ID, Some(&1)),
+			Error::<Test>::NotAllowedWhileRented
+		);
+
+		run_to_block(11);
+
+		assert_ok!(<Rent as Transfer<u64>>::transfer(&None, &COLLECTIBLE_ID, &3));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessor, 3);
+
+		assert_noop!(
+			<Rent as Mutate<u64>>::burn(&None, &COLLECTIBLE_ID, Some(&1)),
+			Error::<Test>::NotLessor
+		);
+		assert_ok!(<Rent as Mutate<u64>>::burn(&None, &COLLECTIBLE_ID, Some(&3)));
+		assert!(!Collectibles::<Test>::contains_key(COLLECTIBLE_ID));
+	});
+}
+
+#[test]
+fn test_nonfungibles_create_collection_and_mint_into() {
+	ExtBuilder::default().build_and_execute(|| {
+		assert_ok!(<Rent as Create<u64>>::create_collection(&Some(5), &1, &1));
+		assert_noop!(
+			<Rent as Create<u64>>::create_collection(&Some(5), &2, &2),
+			Error::<Test>::CollectionExists
+		);
+		assert_noop!(
+			<Rent as Create<u64>>::create_collection(&None, &1, &1),
+			Error::<Test>::CollectionRequired
+		);
+		assert_eq!(NextCollectionId::<Test>::get(), 6);
+		assert_eq!(<Rent as Inspect<u64>>::collection_owner(&Some(5)), Some(1));
+
+		assert_noop!(
+			<Rent as Mutate<u64>>::mint_into(&Some(6), &COLLECTIBLE_ID, &2),
+			Error::<Test>::NoCollection
+		);
+		assert_ok!(<Rent as Mutate<u64>>::mint_into(&Some(5), &COLLECTIBLE_ID, &2));
+
+		assert_eq!(<Rent as Inspect<u64>>::owner(&Some(5), &COLLECTIBLE_ID), Some(2));
+		assert_eq!(LessorCollectibles::<Test>::get(2).unwrap().into_inner(), vec![COLLECTIBLE_ID]);
+	});
+}
+
+#[test]
+fn test_nonfungibles_set_attribute_reserves_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(<Rent as Mutate<u64>>::set_attribute(&None, &COLLECTIBLE_ID, b"level", b"10"));
+		// Base deposit of 100 plus 10 per byte
+		assert_eq!(Balances::reserved_balance(1), 170);
+
+		assert_noop!(
+			<Rent as Mutate<u64>>::set_attribute(&None, &COLLECTIBLE_ID, &[0; 17], b"10"),
+			Error::<Test>::AttributeTooLong
+		);
+	});
+}
+
+// Mints item 0 of collection 0 of pallet-uniques for account 1 and wraps it.
+fn wrap_nft() -> [u8; 16] {
+	assert_ok!(Uniques::create(RuntimeOrigin::signed(1), 0, 1));
+	assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 0, 1));
+	assert_ok!(Rent::wrap_nft(RuntimeOrigin::signed(1), 0, 0));
+	NftCollectibles::<Test>::get(0, 0).unwrap()
+}
+
+#[test]
+fn test_wrap_nft() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = wrap_nft();
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::NftWrapped {
+			collectible: collectible_id,
+			collection: 0,
+			item: 0,
+		}));
+		assert_eq!(<Uniques as Inspect<u64>>::owner(&0, &0), Some(Rent::account_id()));
+		assert_eq!(Collectibles::<Test>::get(collectible_id).unwrap().lessor, 1);
+		assert_eq!(LessorCollectibles::<Test>::get(1).unwrap().into_inner(), vec![collectible_id]);
+
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(1), 0, 1, 2));
+		assert_noop!(Rent::wrap_nft(RuntimeOrigin::signed(1), 0, 1), Error::<Test>::NotNftOwner);
+	});
+}
+
+#[test]
+fn test_rent_wrapped_nft() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = wrap_nft();
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 100, 10, 30, None));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), collectible_id, 10, false));
+
+		assert_eq!(Rent::nft_user(&0, &0), Some(2));
+		assert_noop!(
+			Rent::burn(RuntimeOrigin::signed(1), collectible_id),
+			Error::<Test>::NotAllowedWhileRented
+		);
+
+		run_to_block(11);
+
+		assert_eq!(Rent::nft_user(&0, &0), None);
+	});
+}
+
+#[test]
+fn test_burn_returns_wrapped_nft_to_lessor() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = wrap_nft();
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), collectible_id, 3, false));
+
+		assert_ok!(Rent::burn(RuntimeOrigin::signed(3), collectible_id));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::NftUnwrapped {
+			collectible: collectible_id,
+			collection: 0,
+			item: 0,
+			lessor: 3,
+		}));
+		assert_eq!(<Uniques as Inspect<u64>>::owner(&0, &0), Some(3));
+		assert!(!NftCollectibles::<Test>::contains_key(0, 0));
+	});
+}
+
+#[test]
+fn test_rental_inspect_follows_recurring_rental() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_eq!(Rent::user_of(&COLLECTIBLE_ID), None);
+		assert_eq!(Rent::user_expires(&COLLECTIBLE_ID), None);
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		assert_eq!(Rent::user_of(&COLLECTIBLE_ID), Some(2));
+		assert_eq!(Rent::user_expires(&COLLECTIBLE_ID), Some(11));
+		assert!(Rent::is_user(&COLLECTIBLE_ID, &2));
+		assert!(!Rent::is_user(&COLLECTIBLE_ID, &1));
+
+		run_to_block(11);
+
+		assert_eq!(Rent::user_expires(&COLLECTIBLE_ID), Some(21));
+
+		assert_ok!(Rent::set_recurring(RuntimeOrigin::signed(2), COLLECTIBLE_ID, false));
+		run_to_block(21);
+
+		assert_eq!(Rent::user_of(&COLLECTIBLE_ID), None);
+		assert!(!Rent::is_user(&COLLECTIBLE_ID, &2));
+	});
+}
+
+#[test]
+fn test_rental_lifecycle_hooks() {
+	ExtBuilder::default().build_and_execute(|| {
+		RentalLifecycle::set(vec![]);
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		assert_ok!(Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 5));
+
+		run_to_block(16);
+
+		assert_ok!(Rent::set_recurring(RuntimeOrigin::signed(2), COLLECTIBLE_ID, false));
+		run_to_block(26);
+
+		assert_eq!(
+			RentalLifecycle::get(),
+			vec![
+				RentalLifecycleEvent::Started {
+					collectible: COLLECTIBLE_ID,
+					lessee: 2,
+					expires: 11
+				},
+				RentalLifecycleEvent::Renewed {
+					collectible: COLLECTIBLE_ID,
+					lessee: 2,
+					expires: 16
+				},
+				RentalLifecycleEvent::Renewed {
+					collectible: COLLECTIBLE_ID,
+					lessee: 2,
+					expires: 26
+				},
+				RentalLifecycleEvent::Ended { collectible: COLLECTIBLE_ID, lessee: 2 },
+			]
+		);
+	});
+}
+
+#[test]
+fn test_rent_is_priced_by_configured_pricing() {
+	ExtBuilder::default().build_and_execute(|| {
+		RentDiscount::set(Permill::from_percent(20));
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(10), Some(30));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		assert_eq!(Balances::free_balance(2), 1000000000 - 800);
+
+		assert_ok!(Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 5));
+		assert_eq!(Balances::free_balance(2), 1000000000 - 800 - 400);
+
+		run_to_block(16);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 800 - 400 - 800);
+		RentDiscount::set(Permill::zero());
+	});
+}
+
+fn pricing_tiers() -> BoundedVec<PricingTier, ConstU32<3>> {
+	bounded_tiers(&[
+		PricingTier { minimum_blocks: 10, discount: Permill::from_percent(10) },
+		PricingTier { minimum_blocks: 20, discount: Permill::from_percent(25) },
+	])
+}
+
+fn bounded_tiers(tiers: &[PricingTier]) -> BoundedVec<PricingTier, ConstU32<3>> {
+	tiers.to_vec().try_into().unwrap()
+}
+
+#[test]
+fn test_tiered_rent_is_released_and_refunded_pro_rata() {
+	for payment_mode in [PaymentMode::Upfront, PaymentMode::Escrow] {
+		ExtBuilder::default().build_and_execute(|| {
+			mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+			assert_ok!(Rent::set_pricing_tiers(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				pricing_tiers()
+			));
+			assert_ok!(Rent::set_payment_mode(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				payment_mode
+			));
+			assert_ok!(Rent::set_refund_policy(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				RefundPolicy::ProRata
+			));
+
+			assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 20, false));
+
+			System::assert_has_event(RuntimeEvent::Rent(Event::RentPayed {
+				agreement: 0,
+				lessee: 2,
+				lessor: 1,
+				collectible: COLLECTIBLE_ID,
+				total_rent_price: 1500,
+				tier: Some(PricingTier { minimum_blocks: 20, discount: Permill::from_percent(25) }),
+			}));
+
+			run_to_block(11);
+
+			assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+			// Half of the discounted rent paid for the rental period is used
+			System::assert_has_event(RuntimeEvent::Rent(Event::RentRefunded {
+				agreement: 0,
+				lessor: 1,
+				lessee: 2,
+				collectible: COLLECTIBLE_ID,
+				amount: 750,
+			}));
+			assert_eq!(Balances::free_balance(1), 1000000000 + 750);
+			assert_eq!(Balances::free_balance(2), 1000000000 - 750);
+			assert_eq!(Balances::reserved_balance(2), 0);
+		});
+	}
+}
+
+#[test]
+fn test_rent_applies_pricing_tier() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+		assert_ok!(Rent::set_pricing_tiers(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			pricing_tiers()
+		));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 20, true));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentPayed {
+			agreement: 0,
+			lessee: 2,
+			lessor: 1,
+			collectible: COLLECTIBLE_ID,
+			total_rent_price: 1500,
+			tier: Some(PricingTier { minimum_blocks: 20, discount: Permill::from_percent(25) }),
+		}));
+
+		// The extension is priced on its own length
+		assert_ok!(Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 5));
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1500 - 500);
+
+		run_to_block(26);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1500 - 500 - 1500);
+	});
+}
+
+#[test]
+fn test_set_pricing_tiers_should_fail_if_not_sorted() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+
+		assert_noop!(
+			Rent::set_pricing_tiers(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				bounded_tiers(&[
+					PricingTier { minimum_blocks: 20, discount: Permill::from_percent(25) },
+					PricingTier { minimum_blocks: 20, discount: Permill::from_percent(10) },
+				])
+			),
+			Error::<Test>::PricingTiersNotSorted
+		);
+	});
+}
+
+fn dynamic_pricing() -> DynamicPricing<u64> {
+	DynamicPricing { floor: 100, ceiling: 1100, curve: PricingCurve::Linear }
+}
+
+#[test]
+fn test_dynamic_price_follows_utilization() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(50), Some(5), Some(30));
+		assert_ok!(Rent::set_dynamic_pricing(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(dynamic_pricing())
+		));
+
+		// Without rentals the collectible is priced at the floor
+		System::assert_last_event(RuntimeEvent::Rent(Event::PriceAdjusted {
+			collectible: COLLECTIBLE_ID,
+			utilization: Permill::zero(),
+			price_per_block: 100,
+		}));
+		assert_eq!(Rent::quote_rent(COLLECTIBLE_ID, &2, 10), Ok(1000));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+
+		// The renewal is paid at the price of the utilization when it is due
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 2000);
+		System::assert_has_event(RuntimeEvent::Rent(Event::PriceAdjusted {
+			collectible: COLLECTIBLE_ID,
+			utilization: Permill::from_percent(10),
+			price_per_block: 200,
+		}));
+		assert_eq!(Rent::quote_rent(COLLECTIBLE_ID, &2, 10), Ok(2000));
+
+		run_to_block(21);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 2000 - 3000);
+		assert_eq!(Rent::utilization(COLLECTIBLE_ID), Permill::from_percent(20));
+		assert_eq!(Rent::quote_rent(COLLECTIBLE_ID, &2, 10), Ok(3000));
+	});
+}
+
+#[test]
+fn test_dynamic_price_falls_while_idle() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(50), Some(5), Some(30));
+		assert_ok!(Rent::set_dynamic_pricing(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(dynamic_pricing())
+		));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 30, false));
+
+		run_to_block(31);
+		assert_eq!(Rent::quote_rent(COLLECTIBLE_ID, &3, 10), Ok(4000));
+
+		// The rental leaves the utilization window while nobody rents the collectible
+		run_to_block(111);
+		assert_eq!(Rent::quote_rent(COLLECTIBLE_ID, &3, 10), Ok(3000));
+
+		run_to_block(131);
+		assert_eq!(Rent::quote_rent(COLLECTIBLE_ID, &3, 10), Ok(1000));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 10, false));
+		assert_eq!(Balances::free_balance(3), 1000000000 - 1000);
+	});
+}
+
+#[test]
+fn test_dynamic_price_renewal_is_released_and_refunded_at_paid_price() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(50), Some(5), Some(30));
+		assert_ok!(Rent::set_dynamic_pricing(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(dynamic_pricing())
+		));
+		assert_ok!(Rent::set_payment_mode(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			PaymentMode::Escrow
+		));
+		assert_ok!(Rent::set_refund_policy(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			RefundPolicy::ProRata
+		));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		// The renewal is paid at the price of the utilization when it is due
+		run_to_block(11);
+
+		let agreement = RentalAgreements::<Test>::get(0).unwrap();
+		assert_eq!((agreement.period_rent, agreement.price_per_block), (2000, 200));
+
+		run_to_block(16);
+
+		assert_ok!(Rent::claim_rent(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000 + 1000);
+
+		assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentRefunded {
+			agreement: 0,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 1000,
+		}));
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 1000);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn test_dynamic_pricing_counts_only_rented_blocks() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(50), Some(5), Some(30));
+		assert_ok!(Rent::set_dynamic_pricing(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(dynamic_pricing())
+		));
+
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 20, false));
+
+		run_to_block(6);
+		assert_ok!(Rent::end_rental(RuntimeOrigin::signed(2), COLLECTIBLE_ID));
+
+		run_to_block(50);
+		assert_eq!(Rent::utilization(COLLECTIBLE_ID), Permill::from_percent(5));
+
+		// The rental has left the utilization window
+		run_to_block(110);
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 10, false));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::PriceAdjusted {
+			collectible: COLLECTIBLE_ID,
+			utilization: Permill::zero(),
+			price_per_block: 100,
+		}));
+	});
+}
+
+#[test]
+fn test_set_dynamic_pricing_should_fail_if_floor_above_ceiling() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(50), Some(5), Some(30));
+
+		assert_noop!(
+			Rent::set_dynamic_pricing(
+				RuntimeOrigin::signed(1),
+				COLLECTIBLE_ID,
+				Some(DynamicPricing { floor: 200, ceiling: 100, curve: PricingCurve::Quadratic })
+			),
+			Error::<Test>::MinimumMustBeLessThanMaximum
+		);
+		assert_noop!(
+			Rent::set_dynamic_pricing(
+				RuntimeOrigin::signed(2),
+				COLLECTIBLE_ID,
+				Some(dynamic_pricing())
+			),
+			Error::<Test>::NotLessor
+		);
+	});
+}
+
+#[test]
+fn test_escalation_raises_price_on_renewals() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+
+		assert_ok!(Rent::schedule_escalation(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(Escalation::PerRenewal(Permill::from_percent(10)))
+		));
+		System::assert_last_event(RuntimeEvent::Rent(Event::EscalationScheduled {
+			agreement: 0,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			escalation: Escalation::PerRenewal(Permill::from_percent(10)),
+			effective_from: 6,
+		}));
+
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 1100);
+		System::assert_has_event(RuntimeEvent::Rent(Event::PriceEscalated {
+			agreement: 0,
+			collectible: COLLECTIBLE_ID,
+			price_per_block: 110,
+		}));
+		let agreement = RentalAgreements::<Test>::get(0).unwrap();
+		assert_eq!((agreement.period_rent, agreement.price_per_block), (1100, 110));
+
+		run_to_block(21);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 1100 - 1210);
+	});
+}
+
+#[test]
+fn test_escalation_should_not_exceed_collection_policy() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(1), collectible_id, 150, 5, 30, None));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), collectible_id, 10, true));
+
+		assert_ok!(Rent::schedule_escalation(
+			RuntimeOrigin::signed(1),
+			collectible_id,
+			Some(Escalation::PerRenewal(Permill::from_percent(50)))
+		));
+
+		run_to_block(11);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::PriceEscalated {
+			agreement: 0,
+			collectible: collectible_id,
+			price_per_block: 200,
+		}));
+		assert_eq!(RentalAgreements::<Test>::get(0).unwrap().price_per_block, 200);
+
+		run_to_block(21);
+
+		assert_eq!(RentalAgreements::<Test>::get(0).unwrap().period_rent, 2000);
+		assert_eq!(Collectibles::<Test>::get(collectible_id).unwrap().price_per_block, Some(150));
+	});
+}
+
+#[test]
+fn test_escalation_takes_effect_after_notice_period() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 5, true));
+
+		run_to_block(3);
+		assert_ok!(Rent::schedule_escalation(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(Escalation::NewPrice(200))
+		));
+
+		// The renewal in block 6 is within the notice period
+		run_to_block(6);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 500 - 500);
+
+		run_to_block(11);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 500 - 500 - 1000);
+		assert_eq!(RentalAgreements::<Test>::get(0).unwrap().price_per_block, 200);
+
+		// The new price holds for the following renewals, but not for the collectible
+		run_to_block(16);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 500 - 500 - 1000 - 1000);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().price_per_block, Some(100));
+	});
+}
+
+#[test]
+fn test_lessee_can_opt_out_of_escalation() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		assert_ok!(Rent::schedule_escalation(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			Some(Escalation::NewPrice(200))
+		));
+
+		assert_ok!(Rent::set_recurring(RuntimeOrigin::signed(2), COLLECTIBLE_ID, false));
+
+		run_to_block(11);
+
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().price_per_block, Some(100));
+		assert!(!Escalations::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn test_accept_rental_offer_for_unlisted_collectible() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+
+		assert_ok!(Rent::make_rental_offer(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 50, 10, 20));
+		assert_eq!(Balances::reserved_balance(2), 500);
+
+		assert_ok!(Rent::accept_offer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 2));
+
+		System::assert_last_event(RuntimeEvent::Rent(Event::RentalOfferAccepted {
+			agreement: 0,
+			collectible: COLLECTIBLE_ID,
+			lessee: 2,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().price_per_block, None);
+		assert_eq!(RentalAgreements::<Test>::get(0).unwrap().price_per_block, 50);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 500);
+		assert!(!RentalOffers::<Test>::contains_key(COLLECTIBLE_ID, 2));
+		assert!(OfferExpiries::<Test>::get(20).is_empty());
+
+		// The rental ends after the offered blocks
+		run_to_block(11);
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+	});
+}
+
+#[test]
+fn test_rental_offer_expires() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+
+		assert_ok!(Rent::make_rental_offer(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 50, 10, 5));
+		// A new offer replaces the earlier one
+		assert_ok!(Rent::make_rental_offer(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 60, 10, 8));
+		assert_eq!(Balances::reserved_balance(2), 600);
+		assert!(OfferExpiries::<Test>::get(5).is_empty());
+
+		run_to_block(8);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalOfferExpired {
+			collectible: COLLECTIBLE_ID,
+			lessee: 2,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(
+			Rent::accept_offer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 2),
+			Error::<Test>::NoOffer
+		);
+		assert_noop!(
+			Rent::make_rental_offer(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 60, 10, 8),
+			Error::<Test>::OfferExpiryInPast
+		);
+	});
+}
+
+#[test]
+fn test_reject_and_withdraw_rental_offers() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+		assert_ok!(Rent::make_rental_offer(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 50, 10, 20));
+		assert_ok!(Rent::make_rental_offer(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 50, 10, 20));
+
+		assert_noop!(
+			Rent::reject_offer(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 2),
+			Error::<Test>::NotLessor
+		);
+		assert_ok!(Rent::reject_offer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+
+		assert_ok!(Rent::withdraw_offer(RuntimeOrigin::signed(3), COLLECTIBLE_ID));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert!(OfferExpiries::<Test>::get(20).is_empty());
+	});
+}
+
+#[test]
+fn test_auction_rents_to_highest_bidder() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+		assert_ok!(Rent::open_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 10, 50, 10, 5));
+
+		assert_noop!(
+			Rent::bid(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 40),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Rent::bid(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 50));
+		assert_eq!(Balances::reserved_balance(2), 500);
+
+		assert_noop!(
+			Rent::bid(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 55),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Rent::bid(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 60));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 600);
+
+		assert_noop!(
+			Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, false),
+			Error::<Test>::AuctionInProgress
+		);
+
+		run_to_block(5);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::AuctionSettled {
+			agreement: 0,
+			collectible: COLLECTIBLE_ID,
+			lessee: 3,
+			price_per_block: 60,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(3));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1000000000 - 600);
+		assert_eq!(Balances::free_balance(1), 1000000000 + 600);
+		assert_noop!(
+			Rent::bid(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 70),
+			Error::<Test>::NoAuction
+		);
+	});
+}
+
+#[test]
+fn test_auction_can_only_be_cancelled_without_bids() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), Some(5), Some(30));
+		assert_ok!(Rent::open_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 10, 50, 10, 5));
+		assert_ok!(Rent::cancel_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+		assert!(AuctionEnds::<Test>::get(5).is_empty());
+
+		// An auction without bids leaves the collectible unrented
+		assert_ok!(Rent::open_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 10, 50, 10, 5));
+		run_to_block(5);
+		System::assert_has_event(RuntimeEvent::Rent(Event::AuctionEndedWithoutBids {
+			collectible: COLLECTIBLE_ID,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+
+		assert_ok!(Rent::open_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 10, 50, 10, 10));
+		assert_ok!(Rent::bid(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 50));
+		assert_noop!(
+			Rent::cancel_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID),
+			Error::<Test>::AuctionHasBids
+		);
+		assert_noop!(
+			Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 30, 40),
+			Error::<Test>::AuctionInProgress
+		);
+	});
+}
+
+#[test]
+fn test_auctioned_collectible_cannot_be_burned_or_transferred() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, None, None, None);
+		assert_ok!(Rent::open_auction(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 10, 50, 10, 5));
+		assert_ok!(Rent::bid(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 50));
+
+		assert_noop!(
+			Rent::burn(RuntimeOrigin::signed(1), COLLECTIBLE_ID),
+			Error::<Test>::AuctionInProgress
+		);
+		assert_noop!(
+			Rent::transfer(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 3, false),
+			Error::<Test>::AuctionInProgress
+		);
+		assert!(!<Rent as Inspect<u64>>::can_transfer(&None, &COLLECTIBLE_ID));
+
+		// The bid is settled as usual
+		run_to_block(5);
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+		assert_eq!(Balances::free_balance(1), 1000000000 + 500);
+	});
+}
+
+#[test]
+fn test_reservation_starts_rental_in_start_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), None, None);
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, 20));
+		System::assert_last_event(RuntimeEvent::Rent(Event::RentalReserved {
+			collectible: COLLECTIBLE_ID,
+			lessee: 2,
+			start: 10,
+			end: 20,
+			prepaid: 1000,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 1000);
+
+		// Reservations and rentals must end before the next reservation starts
+		assert_noop!(
+			Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 15, 25),
+			Error::<Test>::ReservationConflict
+		);
+		assert_noop!(
+			Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 20, 30),
+			Error::<Test>::ReservationConflict
+		);
+		assert_noop!(
+			Rent::rent(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 10, false),
+			Error::<Test>::ReservationConflict
+		);
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 5, false));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 21, 30));
+
+		// The prepaid rent is settled as quoted, in the payment mode of the collectible by then
+		run_to_block(7);
+		assert_ok!(Rent::set_payment_mode(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			PaymentMode::Escrow
+		));
+		assert_ok!(Rent::set_pricing_tiers(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			pricing_tiers()
+		));
+
+		run_to_block(10);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::ReservationActivated {
+			agreement: 1,
+			collectible: COLLECTIBLE_ID,
+			lessee: 2,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(2));
+		assert_eq!(LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID).unwrap().next_rent_block, 20);
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentPayed {
+			agreement: 1,
+			lessor: 1,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			total_rent_price: 1000,
+			tier: None,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 1000);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+		assert_eq!(Balances::free_balance(1), 1000000000 + 500);
+
+		run_to_block(21);
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(3));
+		assert!(Reservations::<Test>::get(COLLECTIBLE_ID).is_empty());
+	});
+}
+
+#[test]
+fn test_reservation_pays_royalty_to_collection_creator() {
+	ExtBuilder::default().build_and_execute(|| {
+		let collectible_id = mint_into_collection(collection_policy());
+		assert_ok!(Rent::transfer(RuntimeOrigin::signed(1), collectible_id, 3, false));
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(3), collectible_id, 100, 10, 30, None));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(2), collectible_id, 10, 20));
+
+		run_to_block(10);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RoyaltyPaid {
+			agreement: 0,
+			collection: 0,
+			creator: 1,
+			collectible: collectible_id,
+			amount: 100,
+		}));
+		assert_eq!(Balances::free_balance(1), 1000000000 + 100);
+		assert_eq!(Balances::free_balance(3), 1000000000 + 900);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000);
+		assert_eq!(Balances::reserved_balance(2), 0);
+	});
+}
+
+#[test]
+fn test_reservation_escrows_prepaid_rent() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), None, None);
+		assert_ok!(Rent::set_payment_mode(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			PaymentMode::Escrow
+		));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, 20));
+
+		run_to_block(10);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentEscrowed {
+			agreement: 0,
+			lessee: 2,
+			collectible: COLLECTIBLE_ID,
+			amount: 1000,
+		}));
+		assert_eq!(Balances::reserved_balance(2), 1000);
+
+		run_to_block(15);
+
+		assert_ok!(Rent::claim_rent(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+		assert_eq!(Balances::free_balance(1), 1000000000 + 500);
+		assert_eq!(Balances::reserved_balance(2), 500);
+	});
+}
+
+#[test]
+fn test_reservation_reserves_security_deposit() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), None, None);
+		assert_ok!(Rent::set_rentable(
+			RuntimeOrigin::signed(1),
+			COLLECTIBLE_ID,
+			100,
+			5,
+			30,
+			Some(1000)
+		));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, 20));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 30, 40));
+
+		run_to_block(10);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::DepositReserved {
+			agreement: 0,
+			lessee: 2,
+			amount: 1000,
+		}));
+		assert_eq!(Deposits::<Test>::get(0).unwrap().amount, 1000);
+		assert_eq!(Balances::reserved_balance(2), 1000);
+		assert_eq!(Balances::free_balance(2), 1000000000 - 1000 - 1000);
+
+		// A lessee who cannot afford the deposit gets the prepaid rent back
+		Balances::make_free_balance_be(&3, 800);
+
+		run_to_block(30);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::ReservationActivationFailed {
+			collectible: COLLECTIBLE_ID,
+			lessee: 3,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 800 + 1000);
+	});
+}
+
+#[test]
+fn test_reservation_should_fail_if_not_rentable() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, false, Some(100), None, None);
+		assert_noop!(
+			Rent::reserve(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, 20),
+			Error::<Test>::RentNotAvailable
+		);
+
+		// A collectible made unrentable after it was reserved is not rented out
+		assert_ok!(Rent::set_rentable(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 100, 5, 30, None));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, 20));
+		assert_ok!(Rent::set_unrentable(RuntimeOrigin::signed(1), COLLECTIBLE_ID));
+
+		run_to_block(10);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::ReservationActivationFailed {
+			collectible: COLLECTIBLE_ID,
+			lessee: 2,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+		assert_eq!(Balances::free_balance(2), 1000000000);
+	});
+}
+
+#[test]
+fn test_cancel_reservation_is_refunded_before_cutoff() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), None, None);
+		assert_ok!(Rent::set_reservation_cutoff(RuntimeOrigin::signed(1), COLLECTIBLE_ID, 5));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, 20));
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 30, 40));
+		assert_noop!(
+			Rent::cancel_reservation(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 10),
+			Error::<Test>::NoReservation
+		);
+
+		run_to_block(5);
+
+		assert_ok!(Rent::cancel_reservation(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10));
+		System::assert_last_event(RuntimeEvent::Rent(Event::ReservationCancelled {
+			collectible: COLLECTIBLE_ID,
+			lessee: 2,
+			start: 10,
+			refunded: true,
+		}));
+		assert_eq!(Balances::free_balance(2), 1000000000);
+		assert!(PendingRentals::<Test>::get(10).is_empty());
+
+		// After the cutoff the rent goes to the lessor
+		run_to_block(26);
+
+		assert_ok!(Rent::cancel_reservation(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 30));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 1000000000 - 1000);
+		assert_eq!(Balances::free_balance(1), 1000000000 + 1000);
+
+		run_to_block(30);
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+	});
+}
+
+#[test]
+fn test_recurring_rental_ends_before_reservation() {
+	ExtBuilder::default().build_and_execute(|| {
+		mock::add_collectible(COLLECTIBLE_ID, 1, None, true, Some(100), None, None);
+		assert_ok!(Rent::rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 10, true));
+		let agreement = LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID).unwrap().agreement_id;
+
+		assert_noop!(
+			Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 11, 21),
+			Error::<Test>::ReservationConflict
+		);
+		assert_ok!(Rent::reserve(RuntimeOrigin::signed(3), COLLECTIBLE_ID, 15, 25));
+		assert_noop!(
+			Rent::extend_rent(RuntimeOrigin::signed(2), COLLECTIBLE_ID, 5),
+			Error::<Test>::ReservationConflict
+		);
+
+		run_to_block(11);
+
+		System::assert_has_event(RuntimeEvent::Rent(Event::RentalProcessingFailed {
+			agreement: Some(agreement),
+			collectible: COLLECTIBLE_ID,
+			reason: RentalProcessingError::ReservationConflict,
+		}));
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, None);
+
+		run_to_block(15);
+
+		assert_eq!(Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap().lessee, Some(3));
+	});
+}
+
+#[test]
+fn test_migration_to_v1_records_agreements_of_ongoing_rentals() {
+	ExtBuilder::default().build_and_execute(|| {
+		StorageVersion::new(0).put::<Rent>();
+		let collectible = OldCollectible::<Test> {
+			collectible_id: COLLECTIBLE_ID,
+			price_per_block: Some(100),
+			lessor: 1,
+			lessee: Some(2),
+			rentable: true,
+			minimum_rental_period: None,
+			maximum_rental_period: None,
+		};
+		unhashed::put(&Collectibles::<Test>::hashed_key_for(COLLECTIBLE_ID), &collectible);
+		let rental_config = OldRentalPeriodConfig::<Test> {
+			rental_periodic_interval: 10,
+			next_rent_block: 15,
+			recurring: true,
+		};
+		unhashed::put(
+			&LesseeCollectibles::<Test>::hashed_key_for(2, COLLECTIBLE_ID),
+			&rental_config,
+		);
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Rent::on_chain_storage_version(), 1);
+		let collectible = Collectibles::<Test>::get(COLLECTIBLE_ID).unwrap();
+		assert_eq!(
+			(collectible.lessee, collectible.deposit, collectible.collection),
+			(Some(2), None, None)
+		);
+		let rental_config = LesseeCollectibles::<Test>::get(2, COLLECTIBLE_ID).unwrap();
+		assert_eq!(rental_config.status, RentalStatus::Active);
+		assert_eq!(rental_config.next_rent_block, 15);
+		let agreement = RentalAgreements::<Test>::get(rental_config.agreement_id).unwrap();
+		assert_eq!((agreement.lessor, agreement.lessee), (1, 2));
+		assert_eq!((agreement.period_start, agreement.period_end), (5, 15));
+		assert_eq!(agreement.total_paid, 1000);
+
+		// Migrated storage is left alone
+		MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(NextAgreementId::<Test>::get(), rental_config.agreement_id + 1);
+	});
+}
@@ -0,0 +1,89 @@
+//! Traits for other pallets to query and react to rentals, and to price them.
+
+use frame_support::sp_runtime::{
+	traits::{CheckedMul, Saturating, Zero},
+	DispatchError,
+};
+
+use crate::{BalanceOf, Collectible, Config, Error, Pallet, PricingTier};
+
+/// Answers who may use a rented item, and until when.
+///
+/// The user of an item is its lessee while it is rented. Its owner, the lessor, is not a user.
+pub trait RentalInspect<AccountId, BlockNumber> {
+	/// Type for identifying an item.
+	type ItemId;
+
+	/// Returns the account that may use `item`, if it is rented.
+	fn user_of(item: &Self::ItemId) -> Option<AccountId>;
+
+	/// Returns the block in which the current rental period of `item` ends, if it is rented. A
+	/// recurring rental is renewed in that block if its rent can be collected, so the user may
+	/// keep using the item beyond it.
+	fn user_expires(item: &Self::ItemId) -> Option<BlockNumber>;
+
+	/// Returns `true` if `who` may use `item`.
+	fn is_user(item: &Self::ItemId, who: &AccountId) -> bool {
+		Self::user_of(item).as_ref() == Some(who)
+	}
+}
+
+/// Hooks for other pallets to react to rentals starting, being renewed and ending.
+///
+/// Implemented for tuples, so that several pallets can subscribe.
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+pub trait OnRentalLifecycle<AccountId, ItemId, BlockNumber> {
+	/// Called when `lessee` starts renting `item`, with the block in which its first rental
+	/// period ends.
+	fn on_rental_started(_item: &ItemId, _lessee: &AccountId, _expires: &BlockNumber) {}
+
+	/// Called when the rental of `item` by `lessee` is renewed for another period or extended,
+	/// with the block in which its current rental period now ends.
+	fn on_rental_renewed(_item: &ItemId, _lessee: &AccountId, _expires: &BlockNumber) {}
+
+	/// Called when the rental of `item` by `lessee` ends, for whatever reason.
+	fn on_rental_ended(_item: &ItemId, _lessee: &AccountId) {}
+}
+
+/// Computes the rent of a rental period.
+pub trait RentalPricing<T: Config> {
+	/// Returns the rent `lessee` pays for renting `collectible` for `blocks` blocks, as of block
+	/// `now`.
+	fn rent_price(
+		collectible: &Collectible<T>,
+		lessee: &T::AccountId,
+		blocks: u32,
+		now: T::BlockNumber,
+	) -> Result<BalanceOf<T>, DispatchError>;
+
+	/// Returns the pricing tier `rent_price` applies to renting `collectible` for `blocks`
+	/// blocks, if any.
+	fn applied_tier(_collectible: &Collectible<T>, _blocks: u32) -> Option<PricingTier> {
+		None
+	}
+}
+
+/// Charges the price per block of the collectible for every block, minus the discount of the
+/// pricing tier that applies.
+pub struct LinearPricing;
+
+impl<T: Config> RentalPricing<T> for LinearPricing {
+	fn rent_price(
+		collectible: &Collectible<T>,
+		_lessee: &T::AccountId,
+		blocks: u32,
+		_now: T::BlockNumber,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let price_per_block = collectible.price_per_block.ok_or(Error::<T>::RentNotAvailable)?;
+
+		let price = price_per_block.checked_mul(&blocks.into()).ok_or(Error::<T>::Overflow)?;
+		let discount = Pallet::<T>::pricing_tier(collectible.collectible_id, blocks)
+			.map_or(Zero::zero(), |tier| tier.discount.mul_floor(price));
+
+		Ok(price.saturating_sub(discount))
+	}
+
+	fn applied_tier(collectible: &Collectible<T>, blocks: u32) -> Option<PricingTier> {
+		Pallet::<T>::pricing_tier(collectible.collectible_id, blocks)
+	}
+}
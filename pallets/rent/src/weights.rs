@@ -0,0 +1,1113 @@
+//! Placeholder weights for pallet_rent.
+//!
+//! These weights were NOT produced by the benchmark CLI and are not measurements. They are
+//! estimates written by hand: the storage accesses listed for each function follow the code paths
+//! covered by `benchmarking.rs`, while the execution times are rough guesses. Replace this file
+//! with the output of `benchmark pallet` run on reference hardware before relying on these weights
+//! in production, see the pallet README.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_rent.
+pub trait WeightInfo {
+	fn mint(n: u32, ) -> Weight;
+	fn burn(n: u32, r: u32, ) -> Weight;
+	fn set_rentable(n: u32, ) -> Weight;
+	fn rent() -> Weight;
+	fn set_unrentable(n: u32, ) -> Weight;
+	fn set_recurring() -> Weight;
+	fn extend_rent() -> Weight;
+	fn equip_collectible(n: u32, ) -> Weight;
+	fn unequip_collectible(n: u32, ) -> Weight;
+	fn process_rental() -> Weight;
+	fn set_refund_policy() -> Weight;
+	fn end_rental() -> Weight;
+	fn set_payment_mode() -> Weight;
+	fn claim_rent() -> Weight;
+	fn claim_deposit() -> Weight;
+	fn dispute_deposit_claim() -> Weight;
+	fn settle_deposit_claim() -> Weight;
+	fn resolve_deposit_dispute() -> Weight;
+	fn pay_overdue() -> Weight;
+	fn transfer(n: u32, ) -> Weight;
+	fn list_for_sale() -> Weight;
+	fn unlist_from_sale() -> Weight;
+	fn buy(n: u32, ) -> Weight;
+	fn set_metadata() -> Weight;
+	fn clear_metadata() -> Weight;
+	fn set_attribute() -> Weight;
+	fn clear_attribute() -> Weight;
+	fn create_collection() -> Weight;
+	fn set_collection_policy() -> Weight;
+	fn mint_into_collection(n: u32, ) -> Weight;
+	fn wrap_nft(n: u32, ) -> Weight;
+	fn set_pricing_tiers() -> Weight;
+	fn set_dynamic_pricing() -> Weight;
+	fn schedule_escalation() -> Weight;
+	fn make_rental_offer() -> Weight;
+	fn accept_offer() -> Weight;
+	fn reject_offer() -> Weight;
+	fn withdraw_offer() -> Weight;
+	fn expire_offers(n: u32, ) -> Weight;
+	fn open_auction() -> Weight;
+	fn bid() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn reserve() -> Weight;
+	fn cancel_reservation() -> Weight;
+	fn set_reservation_cutoff() -> Weight;
+	fn activate_reservation() -> Weight;
+	fn claim_refund() -> Weight;
+	fn settle_auctions(n: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_rent, see the module documentation.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn mint(n: u32, ) -> Weight {
+		Weight::from_ref_time(28_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent WrappedNfts (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent PendingRentals (r:32 w:32)
+	// Storage: System Account (r:32 w:32)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: PalletRent NftCollectibles (r:0 w:1)
+	// Storage: PalletRent DynamicPrices (r:0 w:1)
+	// Storage: PalletRent RentalHistory (r:0 w:1)
+	// Storage: PalletRent ReservationCutoffs (r:0 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `r` is `[0, 32]`.
+	fn burn(n: u32, r: u32, ) -> Weight {
+		Weight::from_ref_time(63_874_000 as u64)
+			.saturating_add(Weight::from_ref_time(64_000 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(9_512_000 as u64).saturating_mul(r as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentableCollectibles (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn set_rentable(n: u32, ) -> Weight {
+		Weight::from_ref_time(31_107_000 as u64)
+			.saturating_add(Weight::from_ref_time(35_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:0 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn rent() -> Weight {
+		Weight::from_ref_time(99_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentableCollectibles (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn set_unrentable(n: u32, ) -> Weight {
+		Weight::from_ref_time(25_219_000 as u64)
+			.saturating_add(Weight::from_ref_time(42_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	fn set_recurring() -> Weight {
+		Weight::from_ref_time(26_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent PendingRentals (r:2 w:2)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn extend_rent() -> Weight {
+		Weight::from_ref_time(85_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn equip_collectible(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_137_000 as u64)
+			.saturating_add(Weight::from_ref_time(24_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn unequip_collectible(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_864_000 as u64)
+			.saturating_add(Weight::from_ref_time(38_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: PalletRent Deposits (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Escalations (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn process_rental() -> Weight {
+		Weight::from_ref_time(105_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RefundPolicies (r:0 w:1)
+	fn set_refund_policy() -> Weight {
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: PalletRent Deposits (r:1 w:1)
+	// Storage: PalletRent RefundPolicies (r:1 w:0)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Escalations (r:0 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent OwedRefunds (r:1 w:1)
+	fn end_rental() -> Weight {
+		Weight::from_ref_time(91_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent PaymentModes (r:0 w:1)
+	fn set_payment_mode() -> Weight {
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rent() -> Weight {
+		Weight::from_ref_time(46_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	fn claim_deposit() -> Weight {
+		Weight::from_ref_time(25_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	fn dispute_deposit_claim() -> Weight {
+		Weight::from_ref_time(24_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn settle_deposit_claim() -> Weight {
+		Weight::from_ref_time(53_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn resolve_deposit_dispute() -> Weight {
+		Weight::from_ref_time(52_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent PendingRentals (r:2 w:2)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn pay_overdue() -> Weight {
+		Weight::from_ref_time(89_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent LessorCollectibles (r:2 w:2)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent Deposits (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn transfer(n: u32, ) -> Weight {
+		Weight::from_ref_time(70_318_000 as u64)
+			.saturating_add(Weight::from_ref_time(46_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent SaleListings (r:0 w:1)
+	fn list_for_sale() -> Weight {
+		Weight::from_ref_time(18_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent SaleListings (r:1 w:1)
+	fn unlist_from_sale() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent SaleListings (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent LessorCollectibles (r:2 w:2)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: PalletRent Deposits (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn buy(n: u32, ) -> Weight {
+		Weight::from_ref_time(93_582_000 as u64)
+			.saturating_add(Weight::from_ref_time(47_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_metadata() -> Weight {
+		Weight::from_ref_time(38_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_metadata() -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_attribute() -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		Weight::from_ref_time(38_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent NextCollectionId (r:1 w:1)
+	// Storage: PalletRent Collections (r:0 w:1)
+	fn create_collection() -> Weight {
+		Weight::from_ref_time(17_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collections (r:1 w:1)
+	fn set_collection_policy() -> Weight {
+		Weight::from_ref_time(18_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn mint_into_collection(n: u32, ) -> Weight {
+		Weight::from_ref_time(31_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: PalletRent WrappedNfts (r:0 w:1)
+	// Storage: PalletRent NftCollectibles (r:0 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn wrap_nft(n: u32, ) -> Weight {
+		Weight::from_ref_time(52_639_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:0 w:1)
+	fn set_pricing_tiers() -> Weight {
+		Weight::from_ref_time(25_116_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:1)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	fn set_dynamic_pricing() -> Weight {
+		Weight::from_ref_time(39_472_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent Escalations (r:0 w:1)
+	fn schedule_escalation() -> Weight {
+		Weight::from_ref_time(32_604_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn make_rental_offer() -> Weight {
+		Weight::from_ref_time(48_390_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:0 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn accept_offer() -> Weight {
+		Weight::from_ref_time(113_218_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_offer() -> Weight {
+		Weight::from_ref_time(37_012_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_ref_time(34_227_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_offers(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(21_481_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent AuctionEnds (r:1 w:1)
+	fn open_auction() -> Weight {
+		Weight::from_ref_time(38_659_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn bid() -> Weight {
+		Weight::from_ref_time(53_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent AuctionEnds (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_ref_time(31_245_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent ReservationCutoffs (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:0)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reserve() -> Weight {
+		Weight::from_ref_time(60_104_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn cancel_reservation() -> Weight {
+		Weight::from_ref_time(50_377_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent ReservationCutoffs (r:0 w:1)
+	fn set_reservation_cutoff() -> Weight {
+		Weight::from_ref_time(25_011_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	fn activate_reservation() -> Weight {
+		Weight::from_ref_time(112_640_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: PalletRent OwedRefunds (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_refund() -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent AuctionEnds (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:0 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	/// The range of component `n` is `[0, 16]`.
+	fn settle_auctions(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_302_000 as u64)
+			.saturating_add(Weight::from_ref_time(106_732_000 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((13 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((11 as u64).saturating_mul(n as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn mint(n: u32, ) -> Weight {
+		Weight::from_ref_time(28_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent WrappedNfts (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent PendingRentals (r:32 w:32)
+	// Storage: System Account (r:32 w:32)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: PalletRent NftCollectibles (r:0 w:1)
+	// Storage: PalletRent DynamicPrices (r:0 w:1)
+	// Storage: PalletRent RentalHistory (r:0 w:1)
+	// Storage: PalletRent ReservationCutoffs (r:0 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	/// The range of component `r` is `[0, 32]`.
+	fn burn(n: u32, r: u32, ) -> Weight {
+		Weight::from_ref_time(63_874_000 as u64)
+			.saturating_add(Weight::from_ref_time(64_000 as u64).saturating_mul(n as u64))
+			.saturating_add(Weight::from_ref_time(9_512_000 as u64).saturating_mul(r as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(r as u64)))
+			.saturating_add(RocksDbWeight::get().writes(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(r as u64)))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentableCollectibles (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn set_rentable(n: u32, ) -> Weight {
+		Weight::from_ref_time(31_107_000 as u64)
+			.saturating_add(Weight::from_ref_time(35_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:0 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn rent() -> Weight {
+		Weight::from_ref_time(99_218_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentableCollectibles (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn set_unrentable(n: u32, ) -> Weight {
+		Weight::from_ref_time(25_219_000 as u64)
+			.saturating_add(Weight::from_ref_time(42_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	fn set_recurring() -> Weight {
+		Weight::from_ref_time(26_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent PendingRentals (r:2 w:2)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn extend_rent() -> Weight {
+		Weight::from_ref_time(85_218_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn equip_collectible(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_137_000 as u64)
+			.saturating_add(Weight::from_ref_time(24_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	/// The range of component `n` is `[1, 100]`.
+	fn unequip_collectible(n: u32, ) -> Weight {
+		Weight::from_ref_time(20_864_000 as u64)
+			.saturating_add(Weight::from_ref_time(38_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: PalletRent Deposits (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Escalations (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn process_rental() -> Weight {
+		Weight::from_ref_time(105_218_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RefundPolicies (r:0 w:1)
+	fn set_refund_policy() -> Weight {
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: PalletRent Deposits (r:1 w:1)
+	// Storage: PalletRent RefundPolicies (r:1 w:0)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Escalations (r:0 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent OwedRefunds (r:1 w:1)
+	fn end_rental() -> Weight {
+		Weight::from_ref_time(91_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent PaymentModes (r:0 w:1)
+	fn set_payment_mode() -> Weight {
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_rent() -> Weight {
+		Weight::from_ref_time(46_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	fn claim_deposit() -> Weight {
+		Weight::from_ref_time(25_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	fn dispute_deposit_claim() -> Weight {
+		Weight::from_ref_time(24_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn settle_deposit_claim() -> Weight {
+		Weight::from_ref_time(53_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Deposits (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn resolve_deposit_dispute() -> Weight {
+		Weight::from_ref_time(52_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent PendingRentals (r:2 w:2)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn pay_overdue() -> Weight {
+		Weight::from_ref_time(89_218_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent LessorCollectibles (r:2 w:2)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PalletRent Deposits (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn transfer(n: u32, ) -> Weight {
+		Weight::from_ref_time(70_318_000 as u64)
+			.saturating_add(Weight::from_ref_time(46_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent SaleListings (r:0 w:1)
+	fn list_for_sale() -> Weight {
+		Weight::from_ref_time(18_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent SaleListings (r:1 w:1)
+	fn unlist_from_sale() -> Weight {
+		Weight::from_ref_time(20_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent SaleListings (r:1 w:1)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent LessorCollectibles (r:2 w:2)
+	// Storage: PalletRent AccountEquips (r:1 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent RentalAgreements (r:1 w:1)
+	// Storage: PalletRent Deposits (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn buy(n: u32, ) -> Weight {
+		Weight::from_ref_time(93_582_000 as u64)
+			.saturating_add(Weight::from_ref_time(47_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_metadata() -> Weight {
+		Weight::from_ref_time(38_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_metadata() -> Weight {
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_attribute() -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent MetadataOf (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_attribute() -> Weight {
+		Weight::from_ref_time(38_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent NextCollectionId (r:1 w:1)
+	// Storage: PalletRent Collections (r:0 w:1)
+	fn create_collection() -> Weight {
+		Weight::from_ref_time(17_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collections (r:1 w:1)
+	fn set_collection_policy() -> Weight {
+		Weight::from_ref_time(18_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn mint_into_collection(n: u32, ) -> Weight {
+		Weight::from_ref_time(31_412_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: RandomnessCollectiveFlip RandomMaterial (r:1 w:0)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent LessorCollectibles (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Storage: PalletRent WrappedNfts (r:0 w:1)
+	// Storage: PalletRent NftCollectibles (r:0 w:1)
+	/// The range of component `n` is `[0, 99]`.
+	fn wrap_nft(n: u32, ) -> Weight {
+		Weight::from_ref_time(52_639_000 as u64)
+			.saturating_add(Weight::from_ref_time(31_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:0 w:1)
+	fn set_pricing_tiers() -> Weight {
+		Weight::from_ref_time(25_116_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:1)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	fn set_dynamic_pricing() -> Weight {
+		Weight::from_ref_time(39_472_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent LesseeCollectibles (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent Escalations (r:0 w:1)
+	fn schedule_escalation() -> Weight {
+		Weight::from_ref_time(32_604_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:2 w:2)
+	// Storage: System Account (r:1 w:1)
+	fn make_rental_offer() -> Weight {
+		Weight::from_ref_time(48_390_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:0 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	fn accept_offer() -> Weight {
+		Weight::from_ref_time(113_218_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_offer() -> Weight {
+		Weight::from_ref_time(37_012_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn withdraw_offer() -> Weight {
+		Weight::from_ref_time(34_227_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent OfferExpiries (r:1 w:1)
+	// Storage: PalletRent RentalOffers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	/// The range of component `n` is `[0, 64]`.
+	fn expire_offers(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_118_000 as u64)
+			.saturating_add(Weight::from_ref_time(21_481_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent AuctionEnds (r:1 w:1)
+	fn open_auction() -> Weight {
+		Weight::from_ref_time(38_659_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn bid() -> Weight {
+		Weight::from_ref_time(53_870_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent AuctionEnds (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_ref_time(31_245_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent RentalAuctions (r:1 w:0)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent ReservationCutoffs (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:0)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reserve() -> Weight {
+		Weight::from_ref_time(60_104_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn cancel_reservation() -> Weight {
+		Weight::from_ref_time(50_377_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: PalletRent Collectibles (r:1 w:0)
+	// Storage: PalletRent ReservationCutoffs (r:0 w:1)
+	fn set_reservation_cutoff() -> Weight {
+		Weight::from_ref_time(25_011_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: PalletRent LesseeCollectibles (r:1 w:1)
+	// Storage: PalletRent Reservations (r:1 w:1)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	fn activate_reservation() -> Weight {
+		Weight::from_ref_time(112_640_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(11 as u64))
+	}
+	// Storage: PalletRent OwedRefunds (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	fn claim_refund() -> Weight {
+		Weight::from_ref_time(40_000_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: PalletRent AuctionEnds (r:1 w:1)
+	// Storage: PalletRent RentalAuctions (r:1 w:1)
+	// Storage: PalletRent Collectibles (r:1 w:1)
+	// Storage: PalletRent NextAgreementId (r:1 w:1)
+	// Storage: PalletRent PaymentModes (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: PalletRent PendingRentals (r:1 w:1)
+	// Storage: PalletRent Collections (r:1 w:0)
+	// Storage: PalletRent PricingTiers (r:1 w:0)
+	// Storage: PalletRent DynamicPrices (r:1 w:0)
+	// Storage: PalletRent RentalHistory (r:1 w:1)
+	// Storage: PalletRent RentalAgreements (r:0 w:1)
+	// Storage: PalletRent LesseeCollectibles (r:0 w:1)
+	// Storage: PalletRent Deposits (r:0 w:1)
+	// Storage: PalletRent Reservations (r:1 w:0)
+	/// The range of component `n` is `[0, 16]`.
+	fn settle_auctions(n: u32, ) -> Weight {
+		Weight::from_ref_time(5_302_000 as u64)
+			.saturating_add(Weight::from_ref_time(106_732_000 as u64).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((13 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((11 as u64).saturating_mul(n as u64)))
+	}
+}
@@ -30,7 +30,8 @@ use sp_version::RuntimeVersion;
 pub use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem, Randomness, StorageInfo,
+		AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, ConstU8, KeyOwnerProofSystem,
+		Randomness, StorageInfo,
 	},
 	weights::{
 		constants::{
@@ -38,7 +39,7 @@ pub use frame_support::{
 		},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
@@ -103,7 +104,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 101,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -204,12 +205,65 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+parameter_types! {
+	pub MaxRentalProcessingWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const RentPalletId: PalletId = PalletId(*b"py/rentl");
+}
+
 impl pallet_rent::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type CollectionRandomness = RandomnessCollectiveFlip;
 	type MaximumOwned = frame_support::pallet_prelude::ConstU32<100>;
 	type MaximumRentablesPerBlock = frame_support::pallet_prelude::ConstU32<100>;
+	type MaximumSchedulingProbes = ConstU32<10>;
+	type MaxRentalProcessingWeight = MaxRentalProcessingWeight;
+	type PaymentGracePeriod = ConstU32<{ 1 * HOURS }>;
+	type DepositDisputePeriod = ConstU32<{ 2 * DAYS }>;
+	type DisputeOrigin = frame_system::EnsureRoot<AccountId>;
+	type MetadataLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	type MaxAttributes = ConstU32<16>;
+	type MetadataDepositBase = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+	type DepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 5 }>;
+	type PalletId = RentPalletId;
+	type NftCollectionId = u32;
+	type NftId = u32;
+	type Nfts = Uniques;
+	type MaxPricingTiers = ConstU32<8>;
+	type RentalPricing = pallet_rent::LinearPricing;
+	type UtilizationWindow = ConstU32<{ 7 * DAYS }>;
+	type MaxRentalHistory = ConstU32<32>;
+	type EscalationNoticePeriod = ConstU32<{ DAYS }>;
+	type MaxOffersPerBlock = ConstU32<64>;
+	type MaxAuctionsPerBlock = ConstU32<16>;
+	type MaxReservations = ConstU32<32>;
+	type OnRentalLifecycle = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type WeightInfo = pallet_rent::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_uniques::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type Locker = ();
+	type CollectionDeposit = ConstU128<{ 200 * EXISTENTIAL_DEPOSIT }>;
+	type ItemDeposit = ConstU128<{ 2 * EXISTENTIAL_DEPOSIT }>;
+	type MetadataDepositBase = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+	type AttributeDepositBase = ConstU128<{ 20 * EXISTENTIAL_DEPOSIT }>;
+	type DepositPerByte = ConstU128<{ EXISTENTIAL_DEPOSIT / 5 }>;
+	type StringLimit = ConstU32<256>;
+	type KeyLimit = ConstU32<32>;
+	type ValueLimit = ConstU32<64>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 }
 
 impl pallet_randomness_collective_flip::Config for Runtime {}
@@ -299,6 +353,7 @@ construct_runtime!(
 		Balances: pallet_balances,
 		TransactionPayment: pallet_transaction_payment,
 		Sudo: pallet_sudo,
+		Uniques: pallet_uniques,
 	}
 );
 
@@ -332,8 +387,12 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (pallet_rent::migrations::v1::MigrateToV1<Runtime>,);
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -345,6 +404,8 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_balances, Balances]
 		[pallet_timestamp, Timestamp]
+		[pallet_rent, PalletRent]
+		[pallet_uniques, Uniques]
 	);
 }
 
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+# This script is meant to be run on Unix/Linux based systems, on the reference hardware
+set -e
+
+echo "*** Generating the weights of pallet_rent ***"
+
+cd $(dirname ${BASH_SOURCE[0]})/..
+
+cargo build --release -p node-template --features runtime-benchmarks
+
+./target/release/node-template benchmark pallet \
+	--chain=dev \
+	--pallet=pallet_rent \
+	--extrinsic='*' \
+	--steps=50 \
+	--repeat=20 \
+	--execution=wasm \
+	--wasm-execution=compiled \
+	--template=.maintain/frame-weight-template.hbs \
+	--output=pallets/rent/src/weights.rs